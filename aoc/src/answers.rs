@@ -0,0 +1,124 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use common::{parse_field, ParseError};
+
+use crate::days;
+
+/// the answers file, next to the `dayN` directories of the inputs
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// One checked-in answer: running `part` of `day` on `input` has to give `answer`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: u8,
+    /// relative to the day directory
+    pub input: String,
+    pub answer: String,
+}
+
+impl KnownAnswer {
+    /// `day part input answer` per line, empty lines and `#` comments are skipped
+    pub fn parse_all(input: &str) -> Result<Vec<Self>, ParseError> {
+        let mut ret = Vec::new();
+        for (idx, line) in input.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 4 {
+                return Err(ParseError::end_of_line(
+                    idx + 1,
+                    line,
+                    "day, part, input and answer",
+                ));
+            } else if let Some(extra) = fields.get(4) {
+                return Err(ParseError::in_line(idx + 1, line, extra, "end of line"));
+            }
+            let day: u8 = parse_field(idx + 1, line, fields[0], "a day")?;
+            let part: u8 = parse_field(idx + 1, line, fields[1], "a part")?;
+            if day == 0 || day > days::DAYS {
+                return Err(ParseError::in_line(idx + 1, line, fields[0], "a day"));
+            }
+            if part != 1 && part != 2 {
+                return Err(ParseError::in_line(idx + 1, line, fields[1], "1 or 2"));
+            }
+            ret.push(Self {
+                day,
+                part,
+                input: fields[2].to_string(),
+                answer: fields[3].to_string(),
+            });
+        }
+        Ok(ret)
+    }
+
+    pub fn load_all(path: &Path) -> Result<Vec<Self>> {
+        let input =
+            fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))?;
+        Ok(Self::parse_all(&input).map_err(|e| e.with_file(path))?)
+    }
+
+    /// where the input is, with `root` being the directory of the `dayN` directories
+    pub fn input_path(&self, root: &Path) -> PathBuf {
+        root.join(format!("day{}", self.day)).join(&self.input)
+    }
+
+    /// run the solver again, and fail when the answer changed
+    pub fn check(&self, root: &Path) -> Result<()> {
+        let found = days::solve(self.day, self.part, self.input_path(root))?;
+        if found != self.answer {
+            bail!("expected {}, found {}", self.answer, found);
+        }
+        Ok(())
+    }
+}
+
+impl Display for KnownAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day{} part{} ({})", self.day, self.part, self.input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let known = KnownAnswer::parse_all("# comment\n\n6 2 data.txt 2262\n").unwrap();
+        assert_eq!(
+            known,
+            vec![KnownAnswer {
+                day: 6,
+                part: 2,
+                input: "data.txt".to_string(),
+                answer: "2262".to_string(),
+            }]
+        );
+        let error = KnownAnswer::parse_all("6 3 data.txt 2262").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert!(KnownAnswer::parse_all("6 2 data.txt").is_err());
+    }
+
+    #[test]
+    fn test_known_answers() {
+        let root = common::input::workspace_dir();
+        let known = KnownAnswer::load_all(&root.join(ANSWERS_FILE)).unwrap();
+        let failed: Vec<String> = known
+            .iter()
+            .filter_map(|known| {
+                known
+                    .check(&root)
+                    .err()
+                    .map(|e| format!("{}: {}", known, e))
+            })
+            .collect();
+        assert!(failed.is_empty(), "{}", failed.join("\n"));
+    }
+}
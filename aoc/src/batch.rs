@@ -0,0 +1,161 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::report::{self, DayReport};
+
+/// the files of a directory, or the files matching a glob like `inputs/day6/*.txt`;
+/// `*` and `?` only work in the file name, the directory has to be spelled out
+pub fn expand(pattern: &str) -> Result<Vec<PathBuf>> {
+    let path = Path::new(pattern);
+    let (dir, name) = if path.is_dir() {
+        (path, "*")
+    } else {
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty());
+        (dir.unwrap_or(Path::new(".")), name)
+    };
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("cannot read {}", dir.display()))? {
+        let entry = entry?;
+        let matched = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|file| glob(name, file));
+        if matched && entry.file_type()?.is_file() {
+            files.push(entry.path());
+        }
+    }
+    if files.is_empty() {
+        bail!("no input matches {}", pattern);
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// `*` matches any run of characters, `?` any one character
+fn glob(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // the last `*` seen, and where in `name` it started to match
+    let mut star = None;
+    let (mut p, mut n) = (0, 0);
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            // let the `*` take one more character
+            star = Some((star_p, star_n + 1));
+            p = star_p + 1;
+            n = star_n + 1;
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// run `parts` of `day` on every file; a file that fails does not stop the others
+pub fn run(day: u8, parts: &[u8], files: &[PathBuf]) -> Vec<(PathBuf, Result<DayReport>)> {
+    files
+        .iter()
+        .map(|file| (file.clone(), report::run_day(day, parts, file)))
+        .collect()
+}
+
+/// one row per input with the answers and timings, or the error, then how many failed
+pub fn summary(parts: &[u8], results: &[(PathBuf, Result<DayReport>)]) -> String {
+    let mut header = vec!["input".to_string(), "parse".to_string()];
+    for part in parts {
+        header.push(format!("part{}", part));
+        header.push(format!("part{} time", part));
+    }
+    let mut rows = vec![header];
+    let mut errors = Vec::new();
+    for (file, result) in results {
+        let mut row = vec![file.display().to_string()];
+        match result {
+            Ok(report) => {
+                row.push(format!("{:.2?}", report.parse));
+                for (_, answer, time) in &report.parts {
+                    row.push(answer.clone());
+                    row.push(format!("{:.2?}", time));
+                }
+            }
+            Err(e) => {
+                row.push("failed".to_string());
+                errors.push(format!("{}: {:#}", file.display(), e));
+            }
+        }
+        rows.push(row);
+    }
+    let columns = rows[0].len();
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut ret = String::new();
+    for row in rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        ret.push_str(cells.join("  ").trim_end());
+        ret.push('\n');
+    }
+    ret.push_str(&format!(
+        "{} inputs, {} failed",
+        results.len(),
+        errors.len()
+    ));
+    for error in errors {
+        ret.push('\n');
+        ret.push_str(&error);
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob() {
+        assert!(glob("*.txt", "data_test.txt"));
+        assert!(glob("data*2.txt", "data_test2.txt"));
+        assert!(glob("data?.txt", "data1.txt"));
+        assert!(!glob("data?.txt", "data.txt"));
+        assert!(!glob("*.txt", "data.txt.bak"));
+        assert!(glob("*", ""));
+    }
+
+    #[test]
+    fn test_batch() {
+        let dir = common::input::workspace_dir().join("day3");
+        let files = expand(&dir.join("data_test*.txt").to_string_lossy()).unwrap();
+        assert_eq!(files.len(), 2);
+        let results = run(3, &[1], &files);
+        let summary = summary(&[1], &results);
+        let lines: Vec<&str> = summary.lines().collect();
+        assert!(lines[0].starts_with("input"));
+        assert!(lines[1].contains(" 161 "));
+        assert_eq!(lines[3], "2 inputs, 0 failed");
+        assert!(expand(&dir.join("*.nothing").to_string_lossy()).is_err());
+    }
+}
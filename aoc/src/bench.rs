@@ -0,0 +1,135 @@
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use common::Solution;
+
+use crate::{
+    days::{self, DayRunner},
+    report,
+};
+
+/// min, median and max of repeated timings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// `samples` must not be empty, the median of an even count is the lower middle one
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[(samples.len() - 1) / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+/// the timings of one day: parsing, then each part on the parsed input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayTimings {
+    pub day: u8,
+    /// FNV-1a of the input, see `report::fnv1a`
+    pub input_hash: u64,
+    pub parse: Stats,
+    pub parts: Vec<(u8, Stats)>,
+}
+
+impl Display for DayTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day{} parse  {}", self.day, self.parse)?;
+        for (part, stats) in &self.parts {
+            write!(f, "\nday{} part{}  {}", self.day, part, stats)?;
+        }
+        Ok(())
+    }
+}
+
+/// time parse, and each of `parts`, `runs` times over; the input is read only once
+pub fn bench_day(day: u8, parts: &[u8], input: &Path, runs: usize) -> Result<DayTimings> {
+    let text = days::read_input(input)?;
+    let (parse, parts) = days::dispatch(
+        day,
+        Bench {
+            text: &text,
+            parts,
+            runs: runs.max(1),
+        },
+    )?;
+    Ok(DayTimings {
+        day,
+        input_hash: report::fnv1a(text.as_bytes()),
+        parse,
+        parts,
+    })
+}
+
+struct Bench<'a> {
+    text: &'a str,
+    parts: &'a [u8],
+    runs: usize,
+}
+
+impl DayRunner for Bench<'_> {
+    type Output = (Stats, Vec<(u8, Stats)>);
+
+    fn run<S: Solution>(self) -> Result<Self::Output> {
+        let mut parse = Vec::with_capacity(self.runs);
+        let mut parts = vec![Vec::with_capacity(self.runs); self.parts.len()];
+        for _ in 0..self.runs {
+            let start = Instant::now();
+            let solution = S::parse(black_box(self.text))?;
+            parse.push(start.elapsed());
+            for (part, samples) in self.parts.iter().zip(parts.iter_mut()) {
+                let start = Instant::now();
+                black_box(solution.solve(*part));
+                samples.push(start.elapsed());
+            }
+        }
+        Ok((
+            Stats::from_samples(parse),
+            self.parts
+                .iter()
+                .copied()
+                .zip(parts.into_iter().map(Stats::from_samples))
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3), ms(2)]);
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(2),
+                max: ms(5)
+            }
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::{self, Display};
+
+use anyhow::{bail, Result};
+use common::{CrossCheck, Disagreement};
+
+use crate::days;
+
+/// the days implementing `CrossCheck`
+pub const DAYS: [u8; 4] = [2, 7, 10, 11];
+
+/// run the implementations of `day` against each other on `text`
+pub fn cross_check_text(day: u8, text: &str) -> Result<Vec<Disagreement>> {
+    fn check<S: CrossCheck>(text: &str) -> Result<Vec<Disagreement>> {
+        Ok(S::parse(text)?.cross_check())
+    }
+    match day {
+        2 => check::<day2::DataSet>(text),
+        7 => check::<day7::Solution>(text),
+        10 => check::<day10::RoadMap>(text),
+        11 => check::<day11::StoneVec>(text),
+        _ => bail!("day{} has only one implementation to check", day),
+    }
+}
+
+/// What the cross-check of one day found over its generated inputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayCheck {
+    pub day: u8,
+    pub size: usize,
+    pub seeds: Vec<u64>,
+    /// the seed of the input each disagreement was found on
+    pub disagreements: Vec<(u64, Disagreement)>,
+}
+
+/// generate an input of `size` per seed and cross-check `day` on every one of them
+pub fn run(day: u8, size: usize, seeds: impl IntoIterator<Item = u64>) -> Result<DayCheck> {
+    let seeds: Vec<u64> = seeds.into_iter().collect();
+    let mut disagreements = Vec::new();
+    for seed in &seeds {
+        let input = days::generate(day, size, *seed)?;
+        for disagreement in cross_check_text(day, &input)? {
+            disagreements.push((*seed, disagreement));
+        }
+    }
+    Ok(DayCheck {
+        day,
+        size,
+        seeds,
+        disagreements,
+    })
+}
+
+impl Display for DayCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day{}: {} generated inputs of size {}, {} disagreements",
+            self.day,
+            self.seeds.len(),
+            self.size,
+            self.disagreements.len()
+        )?;
+        for (seed, disagreement) in &self.disagreements {
+            write!(f, "\n  seed {}: {}", seed, disagreement)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cross_check() {
+        for day in DAYS {
+            let check = run(day, 12, 0..10).unwrap();
+            assert_eq!(check.disagreements, vec![], "{}", check);
+        }
+        assert!(cross_check_text(1, "1 2").is_err());
+    }
+}
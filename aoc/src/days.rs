@@ -0,0 +1,253 @@
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use common::{input::Named, Progress, Solution};
+use grid::{Animation, Grid, Playback};
+
+pub const DAYS: u8 = 15;
+
+/// Something to do with the puzzle type of one day, `dispatch` picks the type
+pub trait DayRunner {
+    type Output;
+
+    fn run<S: Solution>(self) -> Result<Self::Output>;
+}
+
+/// call `runner` with the puzzle type of `day`
+pub fn dispatch<R: DayRunner>(day: u8, runner: R) -> Result<R::Output> {
+    match day {
+        1 => runner.run::<day1::List>(),
+        2 => runner.run::<day2::DataSet>(),
+        3 => runner.run::<day3::Solution>(),
+        4 => runner.run::<day4::DataSet>(),
+        5 => runner.run::<day5::Dataset>(),
+        6 => runner.run::<day6::LabMap>(),
+        7 => runner.run::<day7::Solution>(),
+        8 => runner.run::<day8::SignalMap>(),
+        9 => runner.run::<day9::Solution>(),
+        10 => runner.run::<day10::RoadMap>(),
+        11 => runner.run::<day11::StoneVec>(),
+        12 => runner.run::<day12::AreaMap>(),
+        13 => runner.run::<day13::Dataset>(),
+        14 => runner.run::<day14::Puzzle>(),
+        15 => runner.run::<day15::Problem>(),
+        _ => bail!("there is no day {}", day),
+    }
+}
+
+/// read the whole input, `-` is stdin
+pub fn read_input(input: &Path) -> Result<String> {
+    let mut text = String::new();
+    if input == Path::new("-") {
+        io::stdin().lock().read_to_string(&mut text)?;
+    } else {
+        text = fs::read_to_string(input)
+            .with_context(|| format!("cannot read {}", input.display()))?;
+    }
+    Ok(text)
+}
+
+/// run one part of one day against the input file, and return the answer as text
+pub fn solve(day: u8, part: u8, input: PathBuf) -> Result<String> {
+    Ok(solve_parts(day, &[part], &input)?.remove(0))
+}
+
+/// parse the input file (`-` for stdin) once, and answer each of `parts` in order
+pub fn solve_parts(day: u8, parts: &[u8], input: &Path) -> Result<Vec<String>> {
+    solve_parts_with(day, parts, input, Limits::default())
+}
+
+/// like `solve_parts`, each part stops with an error once it is over the limits
+pub fn solve_parts_with(
+    day: u8,
+    parts: &[u8],
+    input: &Path,
+    limits: Limits,
+) -> Result<Vec<String>> {
+    dispatch(
+        day,
+        SolveParts {
+            day,
+            parts,
+            input,
+            limits,
+        },
+    )
+}
+
+/// How long each part may run, and whether to show how far it is.
+///
+/// Only the days that report progress can be stopped: day6, day11 and day14.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub max_iterations: Option<u64>,
+    /// a progress line on stderr, redrawn at most 10 times a second
+    pub show_progress: bool,
+}
+
+impl Limits {
+    /// the progress handle of one part, the timeout starts now
+    pub fn progress(self, day: u8, part: u8) -> Progress<'static> {
+        let mut progress = Progress::default();
+        if let Some(timeout) = self.timeout {
+            progress = progress.with_timeout(timeout);
+        }
+        if let Some(max_iterations) = self.max_iterations {
+            progress = progress.with_max_iterations(max_iterations);
+        }
+        if self.show_progress {
+            let mut last = Instant::now();
+            progress = progress.with_callback(move |done, total| {
+                if last.elapsed() < Duration::from_millis(100) {
+                    return;
+                }
+                last = Instant::now();
+                match total {
+                    Some(total) => eprint!(
+                        "\rday{} part{}: {}/{} ({}%)\x1b[K",
+                        day,
+                        part,
+                        done,
+                        total,
+                        done * 100 / total.max(1)
+                    ),
+                    None => eprint!("\rday{} part{}: {} iterations\x1b[K", day, part, done),
+                }
+                let _ = io::stderr().flush();
+            });
+        }
+        progress
+    }
+}
+
+struct SolveParts<'a> {
+    day: u8,
+    parts: &'a [u8],
+    input: &'a Path,
+    limits: Limits,
+}
+
+impl DayRunner for SolveParts<'_> {
+    type Output = Vec<String>;
+
+    fn run<S: Solution>(self) -> Result<Vec<String>> {
+        let solution = if self.input == Path::new("-") {
+            S::from_reader(io::stdin().lock())?
+        } else {
+            S::load(self.input)?
+        };
+        let mut answers = Vec::with_capacity(self.parts.len());
+        for part in self.parts {
+            let mut progress = self.limits.progress(self.day, *part);
+            let answer = solution.solve_with(*part, &mut progress);
+            if self.limits.show_progress {
+                eprint!("\r\x1b[K");
+            }
+            answers.push(answer.with_context(|| format!("part{}", part))?);
+        }
+        Ok(answers)
+    }
+}
+
+/// like `solve_parts`, on an input that is already in memory
+pub fn solve_text(day: u8, parts: &[u8], text: &str) -> Result<Vec<String>> {
+    dispatch(day, SolveText { parts, text })
+}
+
+struct SolveText<'a> {
+    parts: &'a [u8],
+    text: &'a str,
+}
+
+impl DayRunner for SolveText<'_> {
+    type Output = Vec<String>;
+
+    fn run<S: Solution>(self) -> Result<Vec<String>> {
+        let solution = S::parse(self.text)?;
+        Ok(self
+            .parts
+            .iter()
+            .map(|part| solution.solve(*part))
+            .collect())
+    }
+}
+
+/// play the simulation of `part` of `day` in the terminal, only the days that walk something
+/// step by step can; the controls are read from stdin, so the input has to be a file
+pub fn animate(day: u8, part: u8, input: &Path, playback: Playback) -> Result<()> {
+    if input == Path::new("-") {
+        bail!("the animation reads its controls from stdin, the input has to be a file");
+    }
+    let mut animation = Animation::new(io::stdout().lock(), playback).with_stdin_controls();
+    match (day, part) {
+        (6, 1) => day6::LabMap::load(input)?.animate(&mut animation)?,
+        (14, 1) => day14::Puzzle::load(input)?.animate(Some(100), &mut animation)?,
+        (14, 2) => day14::Puzzle::load(input)?.animate(None, &mut animation)?,
+        (15, _) => day15::Problem::load(input)?.animate(part == 2, &mut animation)?,
+        _ => bail!("there is no animation of day{} part{}", day, part),
+    }
+    Ok(())
+}
+
+/// the final state of the grid of `day`, only the days with a picture to show have one;
+/// `name` is the named input `text` is, if any
+pub fn picture(day: u8, text: &str, name: Option<Named>) -> Result<Grid<char>> {
+    Ok(match day {
+        6 => day6::LabMap::parse_named(text, name)?.picture(),
+        8 => day8::SignalMap::parse_named(text, name)?.picture(),
+        10 => day10::RoadMap::parse_named(text, name)?.picture(),
+        12 => day12::AreaMap::parse_named(text, name)?.picture(),
+        14 => day14::Puzzle::parse_named(text, name)?.picture(),
+        15 => day15::Problem::parse_named(text, name)?.picture(),
+        _ => bail!("there is no picture of day{}", day),
+    })
+}
+
+/// the days `picture` can draw
+pub const PICTURE_DAYS: [u8; 6] = [6, 8, 10, 12, 14, 15];
+
+/// a made-up input for `day`, see the `generate` module of each day for what `size` means
+pub fn generate(day: u8, size: usize, seed: u64) -> Result<String> {
+    Ok(match day {
+        1 => day1::generate::generate(size, seed),
+        2 => day2::generate::generate(size, seed),
+        3 => day3::generate::generate(size, seed),
+        4 => day4::generate::generate(size, seed),
+        5 => day5::generate::generate(size, seed),
+        6 => day6::generate::generate(size, seed),
+        7 => day7::generate::generate(size, seed),
+        8 => day8::generate::generate(size, seed),
+        9 => day9::generate::generate(size, seed),
+        10 => day10::generate::generate(size, seed),
+        11 => day11::generate::generate(size, seed),
+        12 => day12::generate::generate(size, seed),
+        13 => day13::generate::generate(size, seed),
+        14 => day14::generate::generate(size, seed),
+        15 => day15::generate::generate(size, seed),
+        _ => bail!("there is no day {}", day),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_inputs() {
+        for day in 1..=DAYS {
+            for seed in 0..5 {
+                let input = generate(day, 12, seed).unwrap();
+                assert_eq!(input, generate(day, 12, seed).unwrap());
+                if let Err(e) = solve_text(day, &[1, 2], &input) {
+                    panic!("day{} seed {}: {}\n{}", day, seed, e, input);
+                }
+            }
+        }
+    }
+}
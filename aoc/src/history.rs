@@ -0,0 +1,273 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use common::{parse_field, ParseError};
+
+use crate::bench::{DayTimings, Stats};
+
+/// the history file, next to `answers.txt`; it is local to each checkout and not committed
+pub const HISTORY_FILE: &str = "bench_history.txt";
+
+/// One benchmarked step of one day, from one run of `--bench --record`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// seconds since the unix epoch, the records of one run share it
+    pub time: u64,
+    /// the short hash of HEAD, with `-dirty` when the tree had changes
+    pub commit: String,
+    pub day: u8,
+    /// `parse`, `part1` or `part2`
+    pub step: String,
+    pub input_hash: u64,
+    pub stats: Stats,
+}
+
+impl Record {
+    /// a record per step of `timings`
+    pub fn from_timings(time: u64, commit: &str, timings: &DayTimings) -> Vec<Self> {
+        let record = |step: String, stats: Stats| Record {
+            time,
+            commit: commit.to_string(),
+            day: timings.day,
+            step,
+            input_hash: timings.input_hash,
+            stats,
+        };
+        let mut ret = vec![record("parse".to_string(), timings.parse)];
+        for (part, stats) in &timings.parts {
+            ret.push(record(format!("part{}", part), *stats));
+        }
+        ret
+    }
+
+    /// `time commit day step input_hash min median max` per line, the times in nanoseconds;
+    /// empty lines and `#` comments are skipped
+    pub fn parse_all(input: &str) -> Result<Vec<Self>, ParseError> {
+        let mut ret = Vec::new();
+        for (idx, line) in input.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 8 {
+                return Err(ParseError::end_of_line(
+                    idx + 1,
+                    line,
+                    "time, commit, day, step, input hash, min, median and max",
+                ));
+            } else if let Some(extra) = fields.get(8) {
+                return Err(ParseError::in_line(idx + 1, line, extra, "end of line"));
+            }
+            if !matches!(fields[3], "parse" | "part1" | "part2") {
+                return Err(ParseError::in_line(idx + 1, line, fields[3], "a step"));
+            }
+            let input_hash = u64::from_str_radix(fields[4], 16)
+                .map_err(|_| ParseError::in_line(idx + 1, line, fields[4], "an input hash"))?;
+            let nanos = |field: &str| -> Result<Duration, ParseError> {
+                Ok(Duration::from_nanos(parse_field(
+                    idx + 1,
+                    line,
+                    field,
+                    "nanoseconds",
+                )?))
+            };
+            ret.push(Self {
+                time: parse_field(idx + 1, line, fields[0], "a time")?,
+                commit: fields[1].to_string(),
+                day: parse_field(idx + 1, line, fields[2], "a day")?,
+                step: fields[3].to_string(),
+                input_hash,
+                stats: Stats {
+                    min: nanos(fields[5])?,
+                    median: nanos(fields[6])?,
+                    max: nanos(fields[7])?,
+                },
+            });
+        }
+        Ok(ret)
+    }
+
+    /// every record of the file, none when there is no file yet
+    pub fn load_all(path: &Path) -> Result<Vec<Self>> {
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let input =
+            fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))?;
+        Ok(Self::parse_all(&input).map_err(|e| e.with_file(path))?)
+    }
+
+    /// add `records` at the end of the file, which is created with a header when missing
+    pub fn append_all(path: &Path, records: &[Self]) -> Result<()> {
+        let mut text = String::new();
+        if !path.exists() {
+            text.push_str("# The --bench --record history, compared by --compare.\n");
+            text.push_str("# time commit day step input_hash min_ns median_ns max_ns\n");
+        }
+        for record in records {
+            text.push_str(&format!("{}\n", record));
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(text.as_bytes()))
+            .with_context(|| format!("cannot write {}", path.display()))
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {:016x} {} {} {}",
+            self.time,
+            self.commit,
+            self.day,
+            self.step,
+            self.input_hash,
+            self.stats.min.as_nanos(),
+            self.stats.median.as_nanos(),
+            self.stats.max.as_nanos()
+        )
+    }
+}
+
+/// the short hash of HEAD, `-dirty` when there are uncommitted changes, `unknown` outside git
+pub fn git_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{}-dirty", commit)
+        }
+        Some(commit) => commit,
+        None => "unknown".to_string(),
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// The latest median of one step against the run recorded before it, on the same input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison<'a> {
+    pub previous: &'a Record,
+    pub latest: &'a Record,
+    /// how much slower the latest run is in percent, negative when it got faster
+    pub change: f64,
+    pub regressed: bool,
+}
+
+impl Display for Comparison<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day{} {} ({:016x})  {:>10.2?} at {} -> {:>10.2?} at {}  {:+.1}%{}",
+            self.latest.day,
+            self.latest.step,
+            self.latest.input_hash,
+            self.previous.stats.median,
+            self.previous.commit,
+            self.latest.stats.median,
+            self.latest.commit,
+            self.change,
+            if self.regressed { "  SLOWER" } else { "" }
+        )
+    }
+}
+
+/// compare the last two runs of every step and input; a step regressed when its median is
+/// more than `threshold` percent slower
+pub fn compare(records: &[Record], threshold: f64) -> Vec<Comparison<'_>> {
+    let mut runs: BTreeMap<(u8, &str, u64), Vec<&Record>> = BTreeMap::new();
+    for record in records {
+        runs.entry((record.day, record.step.as_str(), record.input_hash))
+            .or_default()
+            .push(record);
+    }
+    runs.into_values()
+        .filter_map(|mut runs| {
+            // the file is in time order already, unless it was edited by hand
+            runs.sort_by_key(|record| record.time);
+            let [.., previous, latest] = runs[..] else {
+                return None;
+            };
+            let before = previous.stats.median.as_secs_f64();
+            let after = latest.stats.median.as_secs_f64();
+            let change = (after - before) / before.max(f64::MIN_POSITIVE) * 100.0;
+            Some(Comparison {
+                previous,
+                latest,
+                change,
+                regressed: change > threshold,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(time: u64, step: &str, median: u64) -> Record {
+        let ms = Duration::from_millis;
+        Record {
+            time,
+            commit: format!("c{}", time),
+            day: 11,
+            step: step.to_string(),
+            input_hash: 0xab,
+            stats: Stats {
+                min: ms(median),
+                median: ms(median),
+                max: ms(median),
+            },
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let records = vec![record(1, "parse", 1), record(1, "part2", 112)];
+        let text: String = records.iter().map(|r| format!("{}\n", r)).collect();
+        assert_eq!(Record::parse_all(&text).unwrap(), records);
+        assert!(Record::parse_all("1 c1 11 part3 ab 1 2 3").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let records = vec![
+            record(1, "part1", 10),
+            record(1, "part2", 100),
+            record(2, "part1", 10),
+            record(2, "part2", 900),
+            record(3, "part2", 950),
+        ];
+        let comparisons = compare(&records, 20.0);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        // the last run is compared to the one just before it, not to the first one
+        assert_eq!(comparisons[1].previous.time, 2);
+        assert!(!comparisons[1].regressed);
+        let comparisons = compare(&records[..4], 20.0);
+        assert!(comparisons[1].regressed);
+        assert!(comparisons[1].to_string().ends_with("+800.0%  SLOWER"));
+    }
+}
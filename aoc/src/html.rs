@@ -0,0 +1,217 @@
+//! A self-contained HTML report: the answers and timings of every day, and an SVG picture of
+//! the days with a grid, see `days::picture`.
+
+use std::{fmt::Write as _, path::Path};
+
+use anyhow::Result;
+use common::input::Named;
+use grid::Grid;
+
+use crate::{
+    days,
+    report::{self, DayReport},
+};
+
+/// What the report shows of one day
+pub struct DayPage {
+    pub day: u8,
+    pub report: Result<DayReport>,
+    /// the SVG, for the days with a picture
+    pub picture: Option<Result<String>>,
+}
+
+/// run `parts` of `day` on `input` and draw its picture when it has one
+pub fn run_day(day: u8, parts: &[u8], input: &Path) -> DayPage {
+    let picture = days::PICTURE_DAYS.contains(&day).then(|| {
+        let text = days::read_input(input)?;
+        Ok(svg(day, &days::picture(day, &text, Named::of_path(input))?))
+    });
+    DayPage {
+        day,
+        report: report::run_day(day, parts, input),
+        picture,
+    }
+}
+
+/// the fill of one cell, `None` for the empty ones
+fn colour(day: u8, c: char) -> Option<String> {
+    let hue = |c: char| format!("hsl({}, 60%, 50%)", (c as u32 * 137) % 360);
+    let colour = match (day, c) {
+        (_, '.') => return None,
+        (6, 'X') => "#ffff66",
+        (6 | 15, '#') => "#5a5a6e",
+        // an antinode, the antennas get a colour per frequency
+        (8, '#') => "#ff4444",
+        (8 | 12, c) => return Some(hue(c)),
+        // the higher, the lighter
+        (10, c) => {
+            let height = c.to_digit(10).unwrap_or(0);
+            return Some(format!("hsl(120, 70%, {}%)", 15 + height * 7));
+        }
+        (14, '#') => "#00cc00",
+        (15, 'O' | '[' | ']') => "#c08040",
+        (15, '@') | (6, '^' | '>' | 'v' | '<') => "#ff4444",
+        _ => "#cccccc",
+    };
+    Some(colour.to_string())
+}
+
+/// `picture` as an SVG, one unit per cell; the regions of day12 get their fences drawn
+pub fn svg(day: u8, picture: &Grid<char>) -> String {
+    let (height, width) = (picture.height(), picture.width());
+    let scale = (600 / height.max(width).max(1)).max(2);
+    let mut ret = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">",
+        width,
+        height,
+        width * scale,
+        height * scale
+    );
+    ret.push_str("<rect width=\"100%\" height=\"100%\" fill=\"#0f0f23\"/>");
+    for (row, cells) in picture.rows().enumerate() {
+        // one rectangle per run of cells of the same colour, a real input has thousands
+        let mut col = 0;
+        while col < cells.len() {
+            let fill = colour(day, cells[col]);
+            let start = col;
+            while col < cells.len() && colour(day, cells[col]) == fill {
+                col += 1;
+            }
+            if let Some(fill) = fill {
+                write!(
+                    ret,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"{}\"/>",
+                    start,
+                    row,
+                    col - start,
+                    fill
+                )
+                .unwrap();
+            }
+        }
+    }
+    if day == 12 {
+        ret.push_str("<path fill=\"none\" stroke=\"#0f0f23\" stroke-width=\"0.15\" d=\"");
+        for ((row, col), plant) in picture.iter() {
+            if row == 0 || picture[(row - 1, col)] != *plant {
+                write!(ret, "M{} {}h1", col, row).unwrap();
+            }
+            if col == 0 || picture[(row, col - 1)] != *plant {
+                write!(ret, "M{} {}v1", col, row).unwrap();
+            }
+            if row + 1 == height {
+                write!(ret, "M{} {}h1", col, height).unwrap();
+            }
+            if col + 1 == width {
+                write!(ret, "M{} {}v1", width, row).unwrap();
+            }
+        }
+        ret.push_str("\"/>");
+    }
+    ret.push_str("</svg>");
+    ret
+}
+
+/// `text` with the characters HTML gives a meaning to escaped
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const STYLE: &str =
+    "body { font-family: sans-serif; margin: 2em; background: #0f0f23; color: #cccccc; }
+h1, h2 { color: #00cc00; font-weight: normal; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 0.8em; text-align: left; border-bottom: 1px solid #333340; }
+td.number { text-align: right; font-family: monospace; }
+.error { color: #ff6666; }
+svg { display: block; max-width: 100%; height: auto; border: 1px solid #333340; }";
+
+/// the whole report, nothing in it is loaded from elsewhere
+pub fn page(pages: &[DayPage]) -> String {
+    let mut ret =
+        String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    write!(
+        ret,
+        "<title>Advent of Code 2024</title>\n<style>\n{}\n</style>\n</head>\n<body>\n",
+        STYLE
+    )
+    .unwrap();
+    ret.push_str("<h1>Advent of Code 2024</h1>\n<table>\n");
+    ret.push_str("<tr><th>day</th><th>input</th><th>parse</th><th>part</th><th>answer</th><th>time</th></tr>\n");
+    for page in pages {
+        match &page.report {
+            Ok(report) => {
+                for (part, answer, time) in &report.parts {
+                    writeln!(
+                        ret,
+                        "<tr><td>day{}</td><td>{}</td><td class=\"number\">{:.2?}</td><td class=\"number\">{}</td><td class=\"number\">{}</td><td class=\"number\">{:.2?}</td></tr>",
+                        page.day,
+                        escape(&report.input),
+                        report.parse,
+                        part,
+                        escape(answer),
+                        time
+                    )
+                    .unwrap();
+                }
+            }
+            Err(e) => writeln!(
+                ret,
+                "<tr><td>day{}</td><td colspan=\"5\" class=\"error\">{}</td></tr>",
+                page.day,
+                escape(&format!("{:#}", e))
+            )
+            .unwrap(),
+        }
+    }
+    ret.push_str("</table>\n");
+    for page in pages {
+        match &page.picture {
+            Some(Ok(svg)) => write!(ret, "<h2>day{}</h2>\n{}\n", page.day, svg).unwrap(),
+            Some(Err(e)) => write!(
+                ret,
+                "<h2>day{}</h2>\n<p class=\"error\">{}</p>\n",
+                page.day,
+                escape(&format!("{:#}", e))
+            )
+            .unwrap(),
+            None => {}
+        }
+    }
+    ret.push_str("</body>\n</html>\n");
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{workspace_dir, Locator, Named};
+
+    #[test]
+    fn test_svg() {
+        let picture = Grid::parse("AAB\nAAB\n").unwrap();
+        let svg = svg(12, &picture);
+        // a run of A and a B per row
+        assert_eq!(svg.matches("<rect x=").count(), 4);
+        assert!(svg.contains("M2 0v1"), "{}", svg);
+        assert!(!svg.contains("M1 0v1"), "{}", svg);
+    }
+
+    #[test]
+    fn test_page() {
+        let locator = Locator::new(workspace_dir());
+        let pages = vec![
+            run_day(3, &[1], &locator.named(3, Named::Sample)),
+            run_day(6, &[1, 2], &locator.named(6, Named::Sample)),
+            run_day(10, &[1], Path::new("missing.txt")),
+        ];
+        let html = page(&pages);
+        assert!(html.contains("<td class=\"number\">161</td>"));
+        assert!(html.contains("<td class=\"number\">41</td>"));
+        assert_eq!(html.matches("<svg").count(), 1);
+        assert_eq!(html.matches("class=\"error\"").count(), 2);
+    }
+}
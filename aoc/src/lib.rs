@@ -0,0 +1,15 @@
+pub mod answers;
+pub mod batch;
+pub mod bench;
+pub mod cross;
+pub mod days;
+pub mod history;
+pub mod html;
+#[cfg(test)]
+mod malformed;
+pub mod memory;
+#[cfg(feature = "plugins")]
+pub mod plugin;
+pub mod report;
+pub mod serve;
+pub mod shrink;
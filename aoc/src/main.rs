@@ -0,0 +1,528 @@
+use std::{
+    fs, panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+#[cfg(feature = "plugins")]
+use aoc::plugin;
+use aoc::{
+    answers::{KnownAnswer, ANSWERS_FILE},
+    batch, bench, cross,
+    days::{self, Limits},
+    history::{self, Record, HISTORY_FILE},
+    html, memory, report, serve,
+    shrink::{self, Failure},
+};
+use clap::{error::ErrorKind, value_parser, ArgGroup, CommandFactory, Parser};
+use common::{
+    input::{Locator, Named},
+    log::{self, Filter},
+};
+use grid::Playback;
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+const USAGE: &str = "aoc [--day N] [--part 1|2] [--input NAME|PATH] [--check] [--bench [--runs N] [--record]] [--alloc] [--json] [--html PATH] [--log LEVEL]
+           [--timeout SECS] [--max-iterations N] [--progress] [--plugins DIR]
+       aoc --batch DIR|GLOB --day N [--part 1|2]
+       aoc --compare [--threshold PCT] [--history PATH]
+       aoc --cross-check [--day N] [--cases N] [--size N] [--seed N]
+       aoc --serve ADDR
+       aoc --shrink panic|disagreement --day N [--part 1|2] [--input NAME|PATH] [--output PATH]
+       aoc --generate --day N [--size N] [--seed N]
+       aoc --animate --day 6|14|15 [--part 1|2] [--input NAME|PATH] [--delay MS] [--skip N] [--paused]";
+
+const KEYS: &str =
+    "while animating, type a line and Enter: nothing pauses and then steps one frame, \
+c resumes, q quits, + and - halve and double the delay, a number sets --skip";
+
+/// Run the Advent of Code 2024 days
+// the flags that do something else than printing the answers are the `mode` group, at most
+// one of them can be given
+#[derive(Debug, Parser)]
+#[command(name = "aoc", override_usage = USAGE, after_help = KEYS)]
+#[command(group(ArgGroup::new("mode").args([
+    "check", "bench", "compare", "alloc", "json", "html", "generate", "cross_check", "shrink",
+    "serve", "animate", "batch",
+])))]
+#[command(group(
+    ArgGroup::new("limits")
+        .args(["timeout", "max_iterations", "progress"])
+        .multiple(true)
+        .conflicts_with("mode")
+))]
+#[command(group(ArgGroup::new("recorded").args(["record", "compare"]).multiple(true)))]
+#[command(group(ArgGroup::new("generated").args(["generate", "cross_check"]).multiple(true)))]
+struct Args {
+    /// run only day N, every day is run when omitted
+    #[arg(long, value_name = "N", value_parser = value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// run only one part, both parts are run when omitted
+    #[arg(long, value_name = "1|2", value_parser = value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// real (the default), sample or sample2 to pick the input in dayN, or the path of an
+    /// input file, - for stdin; dayN is looked for in AOC_INPUT_DIR when it is set, in the
+    /// workspace otherwise
+    #[arg(
+        long,
+        value_name = "NAME|PATH",
+        conflicts_with_all = ["check", "generate", "batch", "cross_check"]
+    )]
+    input: Option<String>,
+    /// run the day on every file of DIR, or every file matching GLOB like 'inputs/*.txt', and
+    /// print a table of the answers, timings and failures
+    #[arg(long, value_name = "DIR|GLOB", requires = "day")]
+    batch: Option<String>,
+    /// compare against the known answers in answers.txt instead of printing
+    #[arg(long)]
+    check: bool,
+    /// time parse, part 1 and part 2 separately, and print min/median/max
+    #[arg(long)]
+    bench: bool,
+    /// how many times --bench runs each of them, 10 by default
+    #[arg(long, value_name = "N", requires = "bench", value_parser = value_parser!(u64).range(1..))]
+    runs: Option<u64>,
+    /// add the --bench timings to the history, with the git commit and input hash
+    #[arg(long, requires = "bench")]
+    record: bool,
+    /// compare the last two recorded runs of every day, part and input, and fail when one of
+    /// them got slower
+    #[arg(long)]
+    compare: bool,
+    /// how much slower a median may get before --compare fails, 20 by default
+    #[arg(long, value_name = "PCT", requires = "compare", value_parser = percentage)]
+    threshold: Option<f64>,
+    /// the history file, bench_history.txt next to the dayN directories by default
+    #[arg(long, value_name = "PATH", requires = "recorded")]
+    history: Option<PathBuf>,
+    /// count the allocations, bytes and peak memory of parse, part 1 and part 2; the runner
+    /// has to be built with --features count-alloc
+    #[arg(long)]
+    alloc: bool,
+    /// print one JSON document with the answers, timings and input hashes
+    #[arg(long)]
+    json: bool,
+    /// write one HTML page with the answers, timings and an SVG picture of the days with a
+    /// grid: day6, day8, day10, day12, day14 and day15
+    #[arg(long, value_name = "PATH")]
+    html: Option<PathBuf>,
+    /// print the messages of the days up to error, info, debug or trace on stderr, dayN=LEVEL
+    /// sets the level of one day, joined by commas: info,day12=trace
+    #[arg(long, value_name = "LEVEL", value_parser = Filter::parse)]
+    log: Option<Filter>,
+    /// stop a part with an error once it runs longer than SECS, which may be a fraction
+    #[arg(long, value_name = "SECS", value_parser = seconds)]
+    timeout: Option<Duration>,
+    /// stop a part with an error after N iterations of its main loop
+    #[arg(long, value_name = "N")]
+    max_iterations: Option<u64>,
+    /// show how far a part is on stderr; only day6, day11 and day14 report progress, so only
+    /// they can be stopped by --timeout and --max-iterations
+    #[arg(long)]
+    progress: bool,
+    /// run the days built as plugins in DIR instead of the ones built in, and the days up to
+    /// 25 that only a plugin has; the runner has to be built with --features plugins, a day
+    /// with cargo rustc --release -p dayN --features plugin --crate-type cdylib
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["mode", "limits"])]
+    plugins: Option<PathBuf>,
+    /// print a random input for the day instead of solving it
+    #[arg(long, requires = "day")]
+    generate: bool,
+    /// how big the generated input is, 100 by default, the unit depends on the day
+    #[arg(long, value_name = "N", requires = "generated")]
+    size: Option<usize>,
+    /// the seed of the generated input, 0 by default
+    #[arg(long, value_name = "N", requires = "generated")]
+    seed: Option<u64>,
+    /// run the reference and the fast implementations of day2, day7, day10 and day11 against
+    /// each other on generated inputs, and fail when they disagree
+    #[arg(long)]
+    cross_check: bool,
+    /// how many inputs --cross-check generates per day, from --seed on, 100 by default; their
+    /// --size is 20 by default
+    #[arg(long, value_name = "N", requires = "cross_check")]
+    cases: Option<u64>,
+    /// drop lines, grid rows and columns, or day13 machines from the input for as long as the
+    /// day still panics, or its implementations still disagree, and print the smallest input
+    /// found
+    #[arg(long, value_name = "panic|disagreement", requires = "day")]
+    shrink: Option<Failure>,
+    /// write the --shrink input to PATH instead
+    #[arg(long, value_name = "PATH", requires = "shrink")]
+    output: Option<PathBuf>,
+    /// serve a dashboard on ADDR, like 127.0.0.1:8024, to run the days and see the results
+    /// and the pictures of the grid days in a browser
+    #[arg(long, value_name = "ADDR", conflicts_with_all = ["day", "part", "input"])]
+    serve: Option<String>,
+    /// play the simulation of the part in the terminal, part 1 by default
+    #[arg(long, requires = "day")]
+    animate: bool,
+    /// how long each frame of --animate stays, 50 by default
+    #[arg(long, value_name = "MS", requires = "animate")]
+    delay: Option<u64>,
+    /// draw only one frame out of N + 1, 0 by default
+    #[arg(long, value_name = "N", requires = "animate")]
+    skip: Option<usize>,
+    /// start --animate paused
+    #[arg(long, requires = "animate")]
+    paused: bool,
+}
+
+fn percentage(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(threshold) if threshold >= 0.0 => Ok(threshold),
+        _ => Err(format!("{} is not a percentage", value)),
+    }
+}
+
+fn seconds(value: &str) -> Result<Duration, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("{} is not a positive number of seconds", value))
+}
+
+impl Args {
+    /// what depends on the values of the flags, clap checks the rest
+    fn validate(&self) -> Result<(), String> {
+        let named = self
+            .input
+            .as_ref()
+            .is_none_or(|input| input.parse::<Named>().is_ok());
+        if !named && self.day.is_none() {
+            return Err(
+                "an --input path needs --day, since every day reads a different format".into(),
+            );
+        }
+        if let (Some(day), None) = (self.day, &self.plugins) {
+            if day > days::DAYS {
+                return Err(format!("there is no day {}, only a plugin can add it", day));
+            }
+        }
+        if let (true, Some(day)) = (self.cross_check, self.day) {
+            if !cross::DAYS.contains(&day) {
+                return Err(format!("day{} has only one implementation to check", day));
+            }
+        }
+        Ok(())
+    }
+
+    fn limits(&self) -> Limits {
+        Limits {
+            timeout: self.timeout,
+            max_iterations: self.max_iterations,
+            show_progress: self.progress,
+        }
+    }
+
+    fn playback(&self) -> Playback {
+        let default = Playback::default();
+        Playback {
+            delay: self.delay.map_or(default.delay, Duration::from_millis),
+            skip: self.skip.unwrap_or(default.skip),
+            paused: self.paused,
+        }
+    }
+}
+
+/// run every known answer of the selected days and parts, and report the ones that changed
+fn check(args: &Args) -> Result<()> {
+    let root = Locator::from_env().dir().to_path_buf();
+    let known = KnownAnswer::load_all(&root.join(ANSWERS_FILE))?;
+    let mut failed = 0;
+    let mut checked = 0;
+    for known in known.iter().filter(|known| {
+        args.day.is_none_or(|day| day == known.day)
+            && args.part.is_none_or(|part| part == known.part)
+    }) {
+        checked += 1;
+        match known.check(&root) {
+            Ok(()) => println!("{}: ok", known),
+            Err(e) => {
+                eprintln!("{}: {}", known, e);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        bail!("{} of {} known answers failed", failed, checked);
+    }
+    Ok(())
+}
+
+/// compare the last two runs in the history of the selected days and parts
+fn compare(args: &Args, history: &Path) -> Result<()> {
+    let records: Vec<Record> = Record::load_all(history)?
+        .into_iter()
+        .filter(|record| {
+            args.day.is_none_or(|day| day == record.day)
+                && args
+                    .part
+                    .is_none_or(|part| record.step == format!("part{}", part))
+        })
+        .collect();
+    let comparisons = history::compare(&records, args.threshold.unwrap_or(20.0));
+    if comparisons.is_empty() {
+        bail!("{} has no two runs to compare", history.display());
+    }
+    for comparison in &comparisons {
+        println!("{}", comparison);
+    }
+    let regressed = comparisons.iter().filter(|c| c.regressed).count();
+    if regressed > 0 {
+        bail!("{} of {} steps got slower", regressed, comparisons.len());
+    }
+    Ok(())
+}
+
+/// The days loaded from `--plugins`, which take the place of the days built in
+#[derive(Default)]
+struct Plugins {
+    #[cfg(feature = "plugins")]
+    days: std::collections::BTreeMap<u8, plugin::Loaded>,
+}
+
+impl Plugins {
+    /// every plugin in `dir`; the ones that do not load are reported and skipped, and of two
+    /// plugins of the same day the last file wins
+    #[cfg(feature = "plugins")]
+    fn load(dir: &Path) -> Result<Self> {
+        let mut plugins = Self::default();
+        for (path, loaded) in plugin::discover(dir)? {
+            match loaded {
+                Ok(loaded) => {
+                    eprintln!(
+                        "day{} is the plugin {} in {}",
+                        loaded.day(),
+                        loaded.name(),
+                        path.display()
+                    );
+                    plugins.days.insert(loaded.day(), loaded);
+                }
+                Err(e) => eprintln!("skipped a plugin: {:#}", e),
+            }
+        }
+        Ok(plugins)
+    }
+
+    #[cfg(not(feature = "plugins"))]
+    fn load(_: &Path) -> Result<Self> {
+        bail!("--plugins needs the runner built with --features plugins");
+    }
+
+    /// the days after the ones built in which have a plugin
+    fn extra_days(&self) -> Vec<u8> {
+        #[cfg(feature = "plugins")]
+        return self
+            .days
+            .range(days::DAYS + 1..)
+            .map(|(day, _)| *day)
+            .collect();
+        #[cfg(not(feature = "plugins"))]
+        Vec::new()
+    }
+
+    fn solve(&self, day: u8, parts: &[u8], input: &Path, limits: Limits) -> Result<Vec<String>> {
+        #[cfg(feature = "plugins")]
+        if let Some(plugin) = self.days.get(&day) {
+            return plugin.solve(parts, input);
+        }
+        days::solve_parts_with(day, parts, input, limits)
+    }
+}
+
+fn run(args: Args) -> Result<()> {
+    if args.check {
+        return check(&args);
+    }
+    if let (true, Some(day)) = (args.generate, args.day) {
+        let input = days::generate(day, args.size.unwrap_or(100), args.seed.unwrap_or(0))?;
+        print!("{}", input);
+        return Ok(());
+    }
+    if let Some(addr) = &args.serve {
+        return serve::serve(addr, Locator::from_env());
+    }
+    if args.cross_check {
+        let days = args.day.map_or(cross::DAYS.to_vec(), |day| vec![day]);
+        let seed = args.seed.unwrap_or(0);
+        let mut disagreements = 0;
+        for day in days {
+            let check = cross::run(
+                day,
+                args.size.unwrap_or(20),
+                seed..seed + args.cases.unwrap_or(100),
+            )?;
+            println!("{}", check);
+            disagreements += check.disagreements.len();
+        }
+        if disagreements > 0 {
+            bail!("the implementations disagree {} times", disagreements);
+        }
+        return Ok(());
+    }
+    if let (Some(failure), Some(day)) = (args.shrink, args.day) {
+        let locator = Locator::from_env();
+        let input = locator.locate(day, args.input.as_deref().unwrap_or("real"));
+        let text = days::read_input(&input)?;
+        let parts = args.part.map_or(vec![1, 2], |part| vec![part]);
+        let mut runs = 0;
+        // every run that fails would print its panic
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let shrunk = shrink::shrink(day, &text, |text| {
+            runs += 1;
+            failure.reproduces(day, &parts, text)
+        });
+        panic::set_hook(hook);
+        let shrunk = shrunk.map_err(|e| anyhow!("day{} on {}: {}", day, input.display(), e))?;
+        eprintln!(
+            "shrunk {} bytes to {} in {} runs",
+            text.len(),
+            shrunk.len(),
+            runs
+        );
+        match &args.output {
+            Some(output) => fs::write(output, &shrunk)
+                .with_context(|| format!("cannot write {}", output.display()))?,
+            None => print!("{}", shrunk),
+        }
+        return Ok(());
+    }
+    if args.alloc && !memory::is_counting() {
+        bail!("--alloc needs the runner built with --features count-alloc");
+    }
+    if let (Some(pattern), Some(day)) = (&args.batch, args.day) {
+        let parts = args.part.map_or(vec![1, 2], |part| vec![part]);
+        let results = batch::run(day, &parts, &batch::expand(pattern)?);
+        println!("{}", batch::summary(&parts, &results));
+        if results.iter().any(|(_, result)| result.is_err()) {
+            bail!("some of the inputs failed");
+        }
+        return Ok(());
+    }
+    let locator = Locator::from_env();
+    let history = args
+        .history
+        .clone()
+        .unwrap_or_else(|| locator.dir().join(HISTORY_FILE));
+    if args.compare {
+        return compare(&args, &history);
+    }
+    // one time and commit for the whole run, so that --compare groups it together
+    let (time, commit) = match args.record {
+        true => (history::now(), history::git_commit()),
+        false => (0, String::new()),
+    };
+    if let (true, Some(day)) = (args.animate, args.day) {
+        let input = locator.locate(day, args.input.as_deref().unwrap_or("real"));
+        return days::animate(day, args.part.unwrap_or(1), &input, args.playback());
+    }
+    let plugins = match &args.plugins {
+        Some(dir) => Plugins::load(dir)?,
+        None => Plugins::default(),
+    };
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => (1..=days::DAYS).chain(plugins.extra_days()).collect(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut failed = false;
+    let mut reports = Vec::new();
+    let mut pages = Vec::new();
+    for day in days {
+        let input = locator.locate(day, args.input.as_deref().unwrap_or("real"));
+        if args.bench {
+            match bench::bench_day(day, &parts, &input, args.runs.unwrap_or(10) as usize) {
+                Ok(timings) => {
+                    println!("{}", timings);
+                    if args.record {
+                        Record::append_all(
+                            &history,
+                            &Record::from_timings(time, &commit, &timings),
+                        )?;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("day{}: {}", day, e);
+                    failed = true;
+                }
+            }
+            continue;
+        }
+        if args.alloc {
+            match memory::measure_day(day, &parts, &input) {
+                Ok(usage) => println!("{}", usage),
+                Err(e) => {
+                    eprintln!("day{}: {}", day, e);
+                    failed = true;
+                }
+            }
+            continue;
+        }
+        if args.html.is_some() {
+            let page = html::run_day(day, &parts, &input);
+            if let Err(e) = &page.report {
+                eprintln!("day{}: {:#}", day, e);
+                failed = true;
+            }
+            pages.push(page);
+            continue;
+        }
+        if args.json {
+            let report = report::run_day(day, &parts, &input);
+            failed |= report.is_err();
+            reports.push((day, report));
+            continue;
+        }
+        match plugins.solve(day, &parts, &input, args.limits()) {
+            Ok(answers) => {
+                for (part, answer) in parts.iter().zip(answers) {
+                    println!("day{} part{}: {}", day, part, answer);
+                }
+            }
+            Err(e) => {
+                eprintln!("day{}: {:#}", day, e);
+                failed = true;
+            }
+        }
+    }
+    if args.json {
+        println!("{}", report::to_json(&reports));
+    }
+    if let Some(path) = &args.html {
+        fs::write(path, html::page(&pages))
+            .with_context(|| format!("cannot write {}", path.display()))?;
+        eprintln!("wrote {}", path.display());
+    }
+    if failed {
+        bail!("some of the parts failed");
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args = Args::parse();
+    if let Err(e) = args.validate() {
+        Args::command().error(ErrorKind::ArgumentConflict, e).exit();
+    }
+    if let Some(filter) = args.log.take() {
+        log::set_filter(filter);
+    }
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
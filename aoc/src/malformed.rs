@@ -0,0 +1,226 @@
+//! Broken inputs for every day: each one has to give an error, or answers, but never a panic.
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use anyhow::Result;
+use common::{
+    input::{self, Locator, Named},
+    Rng,
+};
+
+use crate::days::{self, DAYS};
+
+/// Runs broken inputs, and remembers the ones that panic
+#[derive(Default)]
+struct Suite {
+    failures: Vec<String>,
+}
+
+impl Suite {
+    /// the parse error or the answers of both parts, `None` when the day panics
+    fn solve(&mut self, day: u8, case: &str, input: &str) -> Option<Result<Vec<String>>> {
+        let ret = panic::catch_unwind(AssertUnwindSafe(|| days::solve_text(day, &[1, 2], input)));
+        if ret.is_err() {
+            self.failures.push(format!(
+                "day{} panics on the {} input {:?}",
+                day, case, input
+            ));
+        }
+        ret.ok()
+    }
+
+    /// the input has to be refused
+    fn refused(&mut self, day: u8, case: &str, input: &str) {
+        if let Some(Ok(answers)) = self.solve(day, case, input) {
+            self.failures.push(format!(
+                "day{} answers {:?} to the {} input {:?}",
+                day, answers, case, input
+            ));
+        }
+    }
+
+    fn check(self) {
+        assert!(self.failures.is_empty(), "{}", self.failures.join("\n"));
+    }
+}
+
+/// run `cases` on a thread of their own, so a day that never ends fails the test instead of
+/// hanging it
+fn within(timeout: Duration, cases: impl FnOnce(&mut Suite) + Send + 'static) -> Suite {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut suite = Suite::default();
+        cases(&mut suite);
+        let _ = sender.send(suite);
+    });
+    receiver
+        .recv_timeout(timeout)
+        .expect("a day is still running")
+}
+
+fn sample(day: u8) -> String {
+    let path = Locator::new(input::workspace_dir()).named(day, Named::Sample);
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn test_empty() {
+    let mut suite = Suite::default();
+    for day in 1..=DAYS {
+        for input in ["", "\n", "\n\n\n", "   \n"] {
+            suite.refused(day, "empty", input);
+        }
+    }
+    suite.check();
+}
+
+#[test]
+fn test_truncated() {
+    let mut suite = Suite::default();
+    for day in 1..=DAYS {
+        let sample = sample(day);
+        for len in [1, 2, sample.len() / 3, sample.len() / 2, sample.len() - 2] {
+            suite.solve(day, "truncated", &sample[..len]);
+        }
+    }
+    suite.check();
+}
+
+#[test]
+fn test_ragged() {
+    let mut suite = Suite::default();
+    for day in 1..=DAYS {
+        let sample = sample(day);
+        let mut lines: Vec<&str> = sample.lines().collect();
+        if lines.len() < 2 {
+            continue;
+        }
+        lines[1] = &lines[1][..lines[1].len() / 2];
+        suite.solve(day, "ragged", &lines.join("\n"));
+        lines[1] = "";
+        suite.solve(day, "ragged", &lines.join("\n"));
+    }
+    suite.check();
+}
+
+#[test]
+fn test_crlf() {
+    let mut suite = Suite::default();
+    for day in 1..=DAYS {
+        let sample = sample(day);
+        let expected = suite.solve(day, "sample", &sample).map(Result::unwrap);
+        match suite.solve(day, "CRLF", &sample.replace('\n', "\r\n")) {
+            Some(Ok(answers)) if Some(&answers) != expected.as_ref() => {
+                suite.failures.push(format!(
+                    "day{} answers {:?} with CRLF, {:?} without",
+                    day, answers, expected
+                ))
+            }
+            Some(Err(e)) => suite.failures.push(format!("day{} with CRLF: {}", day, e)),
+            _ => {}
+        }
+    }
+    suite.check();
+}
+
+#[test]
+fn test_random_bytes() {
+    let mut suite = Suite::default();
+    let mut rng = Rng::new(16);
+    let every_byte: Vec<u8> = (0..=255).collect();
+    let alphabets: [&[u8]; 3] = [
+        b"0123456789\n",
+        b"0123456789 ,:+=-\npvXYABO#.@^<>v[]",
+        &every_byte,
+    ];
+    for day in 1..=DAYS {
+        for alphabet in alphabets {
+            for _ in 0..20 {
+                let len = rng.below(40);
+                let bytes: Vec<u8> = (0..len).map(|_| *rng.pick(alphabet)).collect();
+                suite.solve(day, "random", &String::from_utf8_lossy(&bytes));
+            }
+        }
+    }
+    suite.check();
+}
+
+#[test]
+fn test_tiny_generated() {
+    let mut suite = Suite::default();
+    for day in 1..=DAYS {
+        for size in 0..3 {
+            for seed in 0..3 {
+                let input = days::generate(day, size, seed).unwrap();
+                suite.solve(day, "tiny generated", &input);
+            }
+        }
+    }
+    suite.check();
+}
+
+/// inputs the puzzles never give but that are still well formed
+#[test]
+fn test_edge_cases() {
+    within(Duration::from_secs(60), |suite| {
+        // a guard boxed in by walls, and one walking in a loop, never leave the map
+        suite.solve(6, "boxed in", "###\n#^#\n###\n");
+        suite.solve(6, "looping", ".#..\n...#\n#^..\n..#.\n");
+        // numbers at the limit of u64, adding, multiplying or concatenating them overflows
+        suite.solve(7, "u64 limit", "18446744073709551615: 9 9\n");
+        suite.solve(
+            7,
+            "u64 limit",
+            "18446744073709551615: 18446744073709551615 99\n",
+        );
+        // the robot and its boxes walk until they meet a wall
+        suite.refused(15, "unwalled", "@.O\n\n>>\n");
+    })
+    .check();
+}
+
+/// numbers far past what the puzzles give: a day may refuse them or answer, but not overflow
+#[test]
+fn test_big_numbers() {
+    let mut suite = Suite::default();
+    suite.solve(1, "u32 limit", &"4294967295   4294967295\n".repeat(3));
+    suite.solve(1, "u64 ids", "18446744073709551615   1\n");
+    suite.solve(3, "u64 operands", "mul(999999999999,999999999999)\n");
+    // every line is true, their sum is past a u64
+    suite.solve(
+        7,
+        "u64 sum",
+        &"18446744073709551615: 18446744073709551615 1\n".repeat(3),
+    );
+    // stones past a u64, and one whose times 2024 is
+    suite.solve(
+        11,
+        "u64 stones",
+        "99999999999999999999 18446744073709551615\n",
+    );
+    suite.solve(11, "u64 stones", "1000000000000000000\n");
+    // a button as big as a u64, and the biggest machine day13 takes
+    suite.solve(
+        13,
+        "u64 button",
+        "Button A: X+18446744073709551615, Y+1\nButton B: X+1, Y+1\nPrize: X=1, Y=1\n",
+    );
+    suite.solve(
+        13,
+        "u32 limit",
+        "Button A: X+4294967294, Y+1\nButton B: X+1, Y+4294967294\nPrize: X=4294967295, Y=4294967295\n",
+    );
+    // a position past the room, or past an isize, and velocities at the limits of an isize
+    suite.solve(14, "isize limit", "p=18446744073709551615,0 v=1,1\n");
+    suite.solve(
+        14,
+        "isize limit",
+        "p=0,0 v=9223372036854775807,-9223372036854775808\n",
+    );
+    suite.check();
+}
@@ -0,0 +1,201 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::{self, Display},
+    hint::black_box,
+    path::Path,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+use anyhow::Result;
+use common::Solution;
+
+use crate::days::{self, DayRunner};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting every allocation on the way.
+///
+/// It only counts once a binary installs it with `#[global_allocator]`, the runner does with
+/// the `count-alloc` feature.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// a growing `Vec` or `String` counts as one more allocation of the new size
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+/// whether `Counting` is the global allocator, otherwise every count stays 0
+pub fn is_counting() -> bool {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(black_box(Box::new(0u64)));
+    ALLOCATIONS.load(Ordering::Relaxed) > before
+}
+
+/// What one step allocated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// the most memory the step held at once, on top of what was live when it started
+    pub peak: usize,
+}
+
+/// run `f` and count what it allocates; the counters are global, so other threads count too
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let ret = f();
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (ret, usage)
+}
+
+/// `bytes` with a binary unit, like 1.5 MiB
+fn human(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "allocs {:>10}  bytes {:>10}  peak {:>10}",
+            self.allocations,
+            human(self.bytes),
+            human(self.peak as u64)
+        )
+    }
+}
+
+/// what parsing, and each part on the parsed input, allocated for one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayUsage {
+    pub day: u8,
+    pub parse: Usage,
+    pub parts: Vec<(u8, Usage)>,
+}
+
+impl Display for DayUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day{} parse  {}", self.day, self.parse)?;
+        for (part, usage) in &self.parts {
+            write!(f, "\nday{} part{}  {}", self.day, part, usage)?;
+        }
+        Ok(())
+    }
+}
+
+/// count the allocations of parse and of each of `parts`; the input is read before counting
+pub fn measure_day(day: u8, parts: &[u8], input: &Path) -> Result<DayUsage> {
+    let text = days::read_input(input)?;
+    let (parse, parts) = days::dispatch(day, Measure { text: &text, parts })?;
+    Ok(DayUsage { day, parse, parts })
+}
+
+struct Measure<'a> {
+    text: &'a str,
+    parts: &'a [u8],
+}
+
+impl DayRunner for Measure<'_> {
+    type Output = (Usage, Vec<(u8, Usage)>);
+
+    fn run<S: Solution>(self) -> Result<Self::Output> {
+        let (solution, parse) = measure(|| S::parse(self.text));
+        let solution = solution?;
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| {
+                // the answer is turned into text outside of the count
+                let (answer, usage) = measure(|| {
+                    if *part == 1 {
+                        black_box(solution.part_one())
+                    } else {
+                        black_box(solution.part_two())
+                    }
+                });
+                drop(answer);
+                (*part, usage)
+            })
+            .collect();
+        Ok((parse, parts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn test_measure() {
+        assert!(is_counting());
+        let (_, usage) = measure(|| {
+            let small = black_box(vec![0u8; 100]);
+            drop(small);
+            black_box(vec![0u8; 1000])
+        });
+        // the other tests allocate at the same time, so only the lower bounds hold
+        assert!(usage.allocations >= 2);
+        assert!(usage.bytes >= 1100);
+        assert!(usage.peak >= 1000);
+        assert_eq!(human(1536), "1.5 KiB");
+    }
+}
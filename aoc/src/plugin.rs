@@ -0,0 +1,257 @@
+//! Loads the days built as plugins, see `common::plugin` for what a plugin exports.
+//!
+//! A plugin of a day replaces the day built into the runner, and a plugin of a day the runner
+//! does not know adds it. The libraries are opened with `dlopen`, so only on unix.
+
+use std::{
+    ffi::{c_char, c_int, c_void, CStr, CString},
+    fs,
+    os::unix::ffi::OsStrExt,
+    path::{Path, PathBuf},
+    ptr,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use common::plugin::{Plugin, ABI_VERSION, SYMBOL_PREFIX};
+
+use crate::days;
+
+/// the days a plugin may be for, one per day of December up to Christmas
+pub const MAX_DAY: u8 = 25;
+
+const RTLD_NOW: c_int = 2;
+
+#[cfg_attr(target_os = "linux", link(name = "dl"))]
+extern "C" {
+    fn dlopen(filename: *const c_char, flag: c_int) -> *mut c_void;
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    fn dlclose(handle: *mut c_void) -> c_int;
+    fn dlerror() -> *mut c_char;
+}
+
+/// the message of the last `dl*` call that failed
+fn last_error() -> String {
+    // SAFETY: dlerror returns null or a NUL-terminated string valid until the next dl* call
+    unsafe {
+        let error = dlerror();
+        if error.is_null() {
+            "unknown error".to_string()
+        } else {
+            CStr::from_ptr(error).to_string_lossy().into_owned()
+        }
+    }
+}
+
+/// An open shared library, closed when dropped
+struct Library {
+    handle: *mut c_void,
+}
+
+impl Library {
+    fn open(path: &Path) -> Result<Self> {
+        let filename = CString::new(path.as_os_str().as_bytes())?;
+        // SAFETY: the name is NUL-terminated; the library runs its initialisers, which is
+        // what loading a plugin means
+        let handle = unsafe { dlopen(filename.as_ptr(), RTLD_NOW) };
+        if handle.is_null() {
+            bail!("{}", last_error());
+        }
+        Ok(Self { handle })
+    }
+
+    fn symbol(&self, name: &str) -> Option<*mut c_void> {
+        let name = CString::new(name).ok()?;
+        // SAFETY: the handle is open and the name NUL-terminated
+        let symbol = unsafe { dlsym(self.handle, name.as_ptr()) };
+        (!symbol.is_null()).then_some(symbol)
+    }
+}
+
+impl Drop for Library {
+    fn drop(&mut self) {
+        // SAFETY: the handle is open, and nothing of the library outlives it
+        unsafe { dlclose(self.handle) };
+    }
+}
+
+/// A day from a plugin
+pub struct Loaded {
+    path: PathBuf,
+    plugin: *const Plugin,
+    /// keeps `plugin` mapped, `None` for the plugins linked into the runner
+    _library: Option<Library>,
+}
+
+impl Loaded {
+    /// open the library at `path` and find the day it exports; the errors name the file
+    pub fn open(path: &Path) -> Result<Self> {
+        let library = Library::open(path)?;
+        let entry = (1..=MAX_DAY)
+            .find_map(|day| library.symbol(&format!("{}{}", SYMBOL_PREFIX, day)))
+            .ok_or_else(|| {
+                anyhow!(
+                    "{} has no {}N function, it is not a plugin",
+                    path.display(),
+                    SYMBOL_PREFIX
+                )
+            })?;
+        // SAFETY: the symbol is the `extern "C" fn() -> *const Plugin` of `export_plugin!`
+        let plugin = unsafe {
+            let entry: extern "C" fn() -> *const Plugin = std::mem::transmute(entry);
+            entry()
+        };
+        // SAFETY: the plugin is static in the library, mapped as long as `library` is
+        Self::new(path, unsafe { &*plugin }, Some(library))
+            .with_context(|| path.display().to_string())
+    }
+
+    fn new(path: &Path, plugin: &Plugin, library: Option<Library>) -> Result<Self> {
+        if plugin.abi_version != ABI_VERSION {
+            bail!(
+                "the plugin has ABI version {}, the runner {}",
+                plugin.abi_version,
+                ABI_VERSION
+            );
+        }
+        if plugin.day == 0 || plugin.day > MAX_DAY {
+            bail!("the plugin is for day {}", plugin.day);
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            plugin,
+            _library: library,
+        })
+    }
+
+    fn plugin(&self) -> &Plugin {
+        // SAFETY: checked in `new`, and the library stays open as long as `self`
+        unsafe { &*self.plugin }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn day(&self) -> u8 {
+        self.plugin().day
+    }
+
+    pub fn name(&self) -> String {
+        // SAFETY: the name is a static NUL-terminated string of the plugin
+        unsafe { CStr::from_ptr(self.plugin().name) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// a copy of a string of the plugin, which is handed back to it
+    fn take(&self, text: *mut c_char) -> Option<String> {
+        if text.is_null() {
+            return None;
+        }
+        // SAFETY: a NUL-terminated string of the plugin, not used once freed
+        unsafe {
+            let copy = CStr::from_ptr(text).to_string_lossy().into_owned();
+            (self.plugin().free_string)(text);
+            Some(copy)
+        }
+    }
+
+    /// the error the plugin wrote
+    fn error(&self, error: *mut c_char) -> anyhow::Error {
+        match self.take(error) {
+            Some(error) => anyhow!(error),
+            None => anyhow!("the plugin failed without a message"),
+        }
+    }
+
+    /// parse `text` once, and answer each of `parts` in order
+    pub fn solve_text(&self, parts: &[u8], text: &str) -> Result<Vec<String>> {
+        let plugin = self.plugin();
+        let mut error = ptr::null_mut();
+        // SAFETY: the input and its length are those of `text`
+        let solution = unsafe { (plugin.parse)(text.as_ptr(), text.len(), &mut error) };
+        if solution.is_null() {
+            return Err(self.error(error));
+        }
+        let answers = parts
+            .iter()
+            .map(|part| {
+                let mut error = ptr::null_mut();
+                // SAFETY: the solution is the one parsed above, not freed yet
+                let answer = unsafe {
+                    if *part == 1 {
+                        (plugin.part_one)(solution, &mut error)
+                    } else {
+                        (plugin.part_two)(solution, &mut error)
+                    }
+                };
+                self.take(answer)
+                    .ok_or_else(|| self.error(error))
+                    .with_context(|| format!("part{}", part))
+            })
+            .collect();
+        // SAFETY: parsed above, and not used after
+        unsafe { (plugin.free)(solution) };
+        answers
+    }
+
+    /// like `solve_text` on an input file, `-` for stdin
+    pub fn solve(&self, parts: &[u8], input: &Path) -> Result<Vec<String>> {
+        self.solve_text(parts, &days::read_input(input)?)
+    }
+}
+
+/// every plugin of `dir`, the `.so` and `.dylib` files, each one loaded or why it could not be
+pub fn discover(dir: &Path) -> Result<Vec<(PathBuf, Result<Loaded>)>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("cannot read {}", dir.display()))? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "so" || extension == "dylib")
+        {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files
+        .into_iter()
+        .map(|path| {
+            let loaded = Loaded::open(&path);
+            (path, loaded)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin() {
+        static PLUGIN: Plugin = Plugin::new::<day3::Solution>(3, c"day3");
+        let loaded = Loaded::new(Path::new("day3"), &PLUGIN, None).unwrap();
+        assert_eq!((loaded.day(), loaded.name().as_str()), (3, "day3"));
+        let sample = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        assert_eq!(loaded.solve_text(&[1], sample).unwrap(), vec!["161"]);
+        assert!(loaded.solve_text(&[1, 2], "").is_err());
+
+        static OLD: Plugin = Plugin {
+            abi_version: 0,
+            ..Plugin::new::<day3::Solution>(3, c"day3")
+        };
+        assert!(Loaded::new(Path::new("old"), &OLD, None).is_err());
+    }
+
+    #[test]
+    fn test_discover() {
+        let dir = std::env::temp_dir().join(format!("aoc-plugins-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("libbroken.so"), "not a library").unwrap();
+        fs::write(dir.join("notes.txt"), "not a plugin").unwrap();
+        let found = discover(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(found.len(), 1);
+        assert!(found[0].1.is_err());
+    }
+}
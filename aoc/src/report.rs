@@ -0,0 +1,207 @@
+use std::{
+    fmt::Write as _,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use common::{input::Named, Solution};
+
+use crate::days::{self, DayRunner, Limits};
+
+/// What one run of one day gave: the answers and how long each step took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u8,
+    pub input: String,
+    /// FNV-1a of the input bytes, to tell which input the answers are for
+    pub input_hash: u64,
+    pub parse: Duration,
+    /// part, answer and how long the part took
+    pub parts: Vec<(u8, String, Duration)>,
+}
+
+/// read the input (`-` for stdin) once, then parse it and answer each of `parts`, timing every step
+pub fn run_day(day: u8, parts: &[u8], input: &Path) -> Result<DayReport> {
+    let text = days::read_input(input)?;
+    run_text(day, parts, &input.display().to_string(), &text)
+}
+
+/// like `run_day` on an input already in memory; `input` names it in the report, an input
+/// name or path also tells the day which named input it is
+pub fn run_text(day: u8, parts: &[u8], input: &str, text: &str) -> Result<DayReport> {
+    run_text_with(day, parts, input, text, Limits::default())
+}
+
+/// like `run_text`, each part stops with an error once it is over the limits
+pub fn run_text_with(
+    day: u8,
+    parts: &[u8],
+    input: &str,
+    text: &str,
+    limits: Limits,
+) -> Result<DayReport> {
+    let name = input
+        .parse()
+        .ok()
+        .or_else(|| Named::of_path(Path::new(input)));
+    let (parse, parts) = days::dispatch(
+        day,
+        Timed {
+            day,
+            text,
+            name,
+            parts,
+            limits,
+        },
+    )?;
+    Ok(DayReport {
+        day,
+        input: input.to_string(),
+        input_hash: fnv1a(text.as_bytes()),
+        parse,
+        parts,
+    })
+}
+
+struct Timed<'a> {
+    day: u8,
+    text: &'a str,
+    name: Option<Named>,
+    parts: &'a [u8],
+    limits: Limits,
+}
+
+impl DayRunner for Timed<'_> {
+    type Output = (Duration, Vec<(u8, String, Duration)>);
+
+    fn run<S: Solution>(self) -> Result<Self::Output> {
+        let start = Instant::now();
+        let solution = S::parse_named(self.text, self.name)?;
+        let parse = start.elapsed();
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let mut progress = self.limits.progress(self.day, *part);
+                let answer = solution
+                    .solve_with(*part, &mut progress)
+                    .with_context(|| format!("part{}", part))?;
+                Ok((*part, answer, start.elapsed()))
+            })
+            .collect::<Result<_>>()?;
+        Ok((parse, parts))
+    }
+}
+
+/// the 64 bit FNV-1a hash, stable across platforms and releases unlike `DefaultHasher`
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// the outcome of every day of a run as one JSON document, the days that failed carry their error
+pub fn to_json(reports: &[(u8, Result<DayReport>)]) -> String {
+    let mut ret = String::from("{\"results\":[");
+    for (idx, (day, report)) in reports.iter().enumerate() {
+        if idx > 0 {
+            ret.push(',');
+        }
+        match report {
+            Ok(report) => {
+                write!(
+                    ret,
+                    "{{\"day\":{},\"input\":{},\"input_hash\":\"{:016x}\",\"parse_ns\":{},\"parts\":[",
+                    report.day,
+                    json_string(&report.input),
+                    report.input_hash,
+                    report.parse.as_nanos()
+                )
+                .unwrap();
+                for (idx, (part, answer, time)) in report.parts.iter().enumerate() {
+                    if idx > 0 {
+                        ret.push(',');
+                    }
+                    write!(
+                        ret,
+                        "{{\"part\":{},\"answer\":{},\"ns\":{}}}",
+                        part,
+                        json_string(answer),
+                        time.as_nanos()
+                    )
+                    .unwrap();
+                }
+                ret.push_str("]}");
+            }
+            Err(e) => {
+                write!(
+                    ret,
+                    "{{\"day\":{},\"error\":{}}}",
+                    day,
+                    json_string(&format!("{:#}", e))
+                )
+                .unwrap();
+            }
+        }
+    }
+    ret.push_str("]}");
+    ret
+}
+
+/// `text` quoted and escaped as a JSON string
+pub fn json_string(text: &str) -> String {
+    let mut ret = String::with_capacity(text.len() + 2);
+    ret.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            '\r' => ret.push_str("\\r"),
+            '\t' => ret.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(ret, "\\u{:04x}", c as u32).unwrap(),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(json_string("a \"b\"\\\n\u{1}"), r#""a \"b\"\\\n\u0001""#);
+        let reports = vec![
+            (
+                1,
+                Ok(DayReport {
+                    day: 1,
+                    input: "day1/data.txt".to_string(),
+                    input_hash: 0xab,
+                    parse: Duration::from_nanos(10),
+                    parts: vec![(1, "11".to_string(), Duration::from_nanos(20))],
+                }),
+            ),
+            (2, Err(anyhow::anyhow!("cannot read day2/data.txt"))),
+        ];
+        assert_eq!(
+            to_json(&reports),
+            concat!(
+                r#"{"results":[{"day":1,"input":"day1/data.txt","input_hash":"00000000000000ab","#,
+                r#""parse_ns":10,"parts":[{"part":1,"answer":"11","ns":20}]},"#,
+                r#"{"day":2,"error":"cannot read day2/data.txt"}]}"#
+            )
+        );
+    }
+}
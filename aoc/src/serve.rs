@@ -0,0 +1,544 @@
+//! A small dashboard over HTTP, for the machine it runs on.
+//!
+//! `GET /` is a page showing the latest results and the pictures of the grid days;
+//! it talks to the JSON endpoints:
+//!
+//! - `GET /days`: every day, its named inputs and whether it has a picture
+//! - `GET /run?day=N[&part=P][&input=NAME]`: answer a day on a named input, `real` by default;
+//!   `POST` instead sends the input itself as the body
+//! - `GET /results`: the latest run of every day and input, like `--json`
+//! - `GET /picture?day=N[&input=NAME]`: the final grid of the day, also as `POST`
+//!
+//! An uploaded input runs with `UPLOAD_LIMITS` and is given up on after `UPLOAD_TIMEOUT`, at
+//! most `MAX_UPLOADS` at once; a day that panics is a 500 rather than a dropped connection.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use common::{
+    input::{Locator, Named},
+    Cancelled,
+};
+
+use crate::{
+    days::{self, Limits},
+    report::{self, json_string, DayReport},
+};
+
+const INDEX: &str = include_str!("dashboard.html");
+
+/// the biggest input a `POST` may send, the real inputs are a few dozen KiB
+const MAX_BODY: usize = 16 << 20;
+
+/// how long each part of an uploaded input may run, only the days reporting progress stop
+/// by themselves
+const UPLOAD_LIMITS: Limits = Limits {
+    timeout: Some(Duration::from_secs(10)),
+    max_iterations: None,
+    show_progress: false,
+};
+
+/// how long an upload is waited for before it is a 503, a day that cannot be stopped goes
+/// on running on its thread
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// how many uploads may be solved at once, given up on ones included, so that uploads a day
+/// cannot stop do not pile up spinning threads
+const MAX_UPLOADS: usize = 4;
+
+/// A request, with only what the endpoints look at
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Request {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    body: String,
+}
+
+impl Request {
+    fn read(reader: &mut impl BufRead) -> Result<Self> {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let mut words = line.split_whitespace();
+        let (Some(method), Some(target)) = (words.next(), words.next()) else {
+            bail!("not an HTTP request: {:?}", line);
+        };
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let query = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (key.to_string(), value.to_string())
+            })
+            .collect();
+        let mut length = 0;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    length = value.trim().parse()?;
+                }
+            }
+        }
+        if length > MAX_BODY {
+            bail!(
+                "the body is {} bytes, at most {} are taken",
+                length,
+                MAX_BODY
+            );
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+        Ok(Self {
+            method: method.to_string(),
+            path: path.to_string(),
+            query,
+            body: String::from_utf8(body).context("the body is not UTF-8")?,
+        })
+    }
+
+    fn param(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn day(&self) -> Result<u8> {
+        let day = self.param("day").ok_or_else(|| anyhow!("day is missing"))?;
+        match day.parse() {
+            Ok(day) if (1..=days::DAYS).contains(&day) => Ok(day),
+            _ => bail!("there is no day {}", day),
+        }
+    }
+
+    fn parts(&self) -> Result<Vec<u8>> {
+        match self.param("part") {
+            None => Ok(vec![1, 2]),
+            Some("1") => Ok(vec![1]),
+            Some("2") => Ok(vec![2]),
+            Some(part) => bail!("part should be 1 or 2, got {}", part),
+        }
+    }
+
+    /// the name and the text of the input: the body of a `POST`, or a named input; paths are
+    /// not taken, the page may not read any file it likes
+    fn input(&self, locator: &Locator, day: u8) -> Result<(String, String)> {
+        if self.is_upload() {
+            return Ok(("upload".to_string(), self.body.clone()));
+        }
+        let name: Named = self
+            .param("input")
+            .unwrap_or("real")
+            .parse()
+            .map_err(|e: String| anyhow!(e))?;
+        let text = days::read_input(&locator.named(day, name))?;
+        Ok((name.to_string(), text))
+    }
+
+    fn is_upload(&self) -> bool {
+        self.method == "POST"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn json(body: String) -> Self {
+        Self {
+            status: 200,
+            content_type: "application/json",
+            body,
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: format!("{{\"error\":{}}}", json_string(message)),
+        }
+    }
+
+    fn write(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        let reason = match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            500 => "Internal Server Error",
+            503 => "Service Unavailable",
+            _ => "",
+        };
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason,
+            self.content_type,
+            self.body.len(),
+            self.body
+        )?;
+        writer.flush()
+    }
+}
+
+/// What the endpoints share: where the inputs are, and the latest run of every day and input
+struct Dashboard {
+    locator: Locator,
+    latest: Mutex<BTreeMap<(u8, String), DayReport>>,
+    /// what an upload runs with, `UPLOAD_LIMITS`, `UPLOAD_TIMEOUT` and `MAX_UPLOADS` but in
+    /// the tests
+    upload_limits: Limits,
+    upload_timeout: Duration,
+    max_uploads: usize,
+    /// the uploads whose thread is still running
+    uploads: Arc<AtomicUsize>,
+}
+
+impl Dashboard {
+    fn handle(&self, request: &Request) -> Response {
+        let route = match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/") => {
+                return Response {
+                    status: 200,
+                    content_type: "text/html; charset=utf-8",
+                    body: INDEX.to_string(),
+                }
+            }
+            ("GET", "/days") => Ok(self.days()),
+            ("GET", "/results") => Ok(self.results()),
+            ("GET" | "POST", "/run") => self.run(request),
+            ("GET" | "POST", "/picture") => self.picture(request),
+            (_, "/" | "/days" | "/results" | "/run" | "/picture") => {
+                return Response::error(405, "method not allowed")
+            }
+            _ => return Response::error(404, "not found"),
+        };
+        route.unwrap_or_else(|e| Response::error(400, &format!("{:#}", e)))
+    }
+
+    fn days(&self) -> Response {
+        let mut body = String::from("{\"days\":[");
+        for day in 1..=days::DAYS {
+            let inputs: Vec<String> = [Named::Real, Named::Sample, Named::Sample2]
+                .into_iter()
+                .filter(|name| self.locator.named(day, *name).is_file())
+                .map(|name| json_string(&name.to_string()))
+                .collect();
+            if day > 1 {
+                body.push(',');
+            }
+            write!(
+                body,
+                "{{\"day\":{},\"inputs\":[{}],\"picture\":{}}}",
+                day,
+                inputs.join(","),
+                days::PICTURE_DAYS.contains(&day)
+            )
+            .unwrap();
+        }
+        body.push_str("]}");
+        Response::json(body)
+    }
+
+    fn results(&self) -> Response {
+        let latest = self.latest.lock().unwrap();
+        let reports: Vec<(u8, Result<DayReport>)> = latest
+            .values()
+            .map(|report| (report.day, Ok(report.clone())))
+            .collect();
+        Response::json(report::to_json(&reports))
+    }
+
+    /// run `solve` on a thread of its own: a panic is a 500, and an upload still running after
+    /// its timeout, or coming while `max_uploads` are running, a 503
+    fn guarded<T: Send + 'static>(
+        &self,
+        request: &Request,
+        solve: impl FnOnce() -> T + Send + 'static,
+    ) -> Result<T, Response> {
+        let uploads = request.is_upload().then(|| Arc::clone(&self.uploads));
+        if let Some(uploads) = &uploads {
+            if uploads.fetch_add(1, Ordering::SeqCst) >= self.max_uploads {
+                uploads.fetch_sub(1, Ordering::SeqCst);
+                return Err(Response::error(
+                    503,
+                    &format!("{} uploads are running already", self.max_uploads),
+                ));
+            }
+        }
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let ret = panic::catch_unwind(AssertUnwindSafe(solve));
+            if let Some(uploads) = uploads {
+                uploads.fetch_sub(1, Ordering::SeqCst);
+            }
+            let _ = sender.send(ret);
+        });
+        let ret = if request.is_upload() {
+            receiver.recv_timeout(self.upload_timeout)
+        } else {
+            receiver.recv().map_err(RecvTimeoutError::from)
+        };
+        match ret {
+            Ok(Ok(ret)) => Ok(ret),
+            Ok(Err(_)) | Err(RecvTimeoutError::Disconnected) => {
+                Err(Response::error(500, "the day panicked"))
+            }
+            Err(RecvTimeoutError::Timeout) => Err(Response::error(
+                503,
+                &format!("gave up after {:?}", self.upload_timeout),
+            )),
+        }
+    }
+
+    fn run(&self, request: &Request) -> Result<Response> {
+        let day = request.day()?;
+        let parts = request.parts()?;
+        let (name, text) = request.input(&self.locator, day)?;
+        let limits = if request.is_upload() {
+            self.upload_limits
+        } else {
+            Limits::default()
+        };
+        let input = name.clone();
+        let report = match self.guarded(request, move || {
+            report::run_text_with(day, &parts, &input, &text, limits)
+        }) {
+            Ok(report) => report,
+            Err(response) => return Ok(response),
+        };
+        let status = match &report {
+            Ok(report) => {
+                self.latest
+                    .lock()
+                    .unwrap()
+                    .insert((day, name), report.clone());
+                200
+            }
+            Err(e) if e.chain().any(|cause| cause.is::<Cancelled>()) => 503,
+            Err(_) => 200,
+        };
+        Ok(Response {
+            status,
+            ..Response::json(report::to_json(&[(day, report)]))
+        })
+    }
+
+    fn picture(&self, request: &Request) -> Result<Response> {
+        let day = request.day()?;
+        let (name, text) = request.input(&self.locator, day)?;
+        let name = name.parse().ok();
+        let picture = match self.guarded(request, move || days::picture(day, &text, name)) {
+            Ok(picture) => picture?,
+            Err(response) => return Ok(response),
+        };
+        let rows: Vec<String> = picture
+            .rows()
+            .map(|row| json_string(&row.iter().collect::<String>()))
+            .collect();
+        Ok(Response::json(format!(
+            "{{\"day\":{},\"rows\":[{}]}}",
+            day,
+            rows.join(",")
+        )))
+    }
+}
+
+/// answer the requests on `addr` until the process is stopped, each on its own thread
+pub fn serve(addr: &str, locator: Locator) -> Result<()> {
+    let listener = TcpListener::bind(addr).with_context(|| format!("cannot listen on {}", addr))?;
+    eprintln!("the dashboard is on http://{}/", listener.local_addr()?);
+    serve_on(listener, locator)
+}
+
+fn serve_on(listener: TcpListener, locator: Locator) -> Result<()> {
+    let dashboard = Arc::new(Dashboard {
+        locator,
+        latest: Mutex::default(),
+        upload_limits: UPLOAD_LIMITS,
+        upload_timeout: UPLOAD_TIMEOUT,
+        max_uploads: MAX_UPLOADS,
+        uploads: Arc::default(),
+    });
+    for stream in listener.incoming() {
+        // a failed accept, out of file descriptors or aborted by the client, only loses that
+        // connection; the pause keeps a lasting failure from spinning
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                common::error!("cannot accept a connection: {}", e);
+                thread::sleep(Duration::from_millis(100));
+                continue;
+            }
+        };
+        let dashboard = Arc::clone(&dashboard);
+        thread::spawn(move || {
+            if let Err(e) = answer(&dashboard, stream) {
+                common::debug!("dropped a connection: {:#}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+fn answer(dashboard: &Dashboard, stream: TcpStream) -> Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+    let mut reader = BufReader::new(&stream);
+    let response = match Request::read(&mut reader) {
+        Ok(request) => dashboard.handle(&request),
+        Err(e) => Response::error(400, &format!("{:#}", e)),
+    };
+    response.write(&mut &stream)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::workspace_dir;
+    use std::io::Read;
+
+    fn request(method: &str, target: &str, body: &str) -> Request {
+        let raw = format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            target,
+            body.len(),
+            body
+        );
+        Request::read(&mut raw.as_bytes()).unwrap()
+    }
+
+    fn dashboard() -> Dashboard {
+        Dashboard {
+            locator: Locator::new(workspace_dir()),
+            latest: Mutex::default(),
+            upload_limits: UPLOAD_LIMITS,
+            upload_timeout: UPLOAD_TIMEOUT,
+            max_uploads: MAX_UPLOADS,
+            uploads: Arc::default(),
+        }
+    }
+
+    #[test]
+    fn test_routes() {
+        let dashboard = dashboard();
+        let response = dashboard.handle(&request("GET", "/run?day=3&part=1&input=sample", ""));
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("\"answer\":\"161\""));
+        let response = dashboard.handle(&request("POST", "/run?day=1", "3 4\n4 3\n"));
+        assert!(response.body.contains("\"input\":\"upload\""));
+        assert!(response.body.contains("\"answer\":\"7\""));
+        let results = dashboard.handle(&request("GET", "/results", "")).body;
+        assert_eq!(results.matches("\"day\":").count(), 2);
+
+        let response = dashboard.handle(&request("GET", "/picture?day=6&input=sample", ""));
+        assert!(
+            response.body.contains("\"....#.....\""),
+            "{}",
+            response.body
+        );
+        assert!(dashboard
+            .handle(&request("GET", "/days", ""))
+            .body
+            .contains("\"picture\":true"));
+
+        let status = |method, target| dashboard.handle(&request(method, target, "")).status;
+        assert_eq!(status("GET", "/run?day=26"), 400);
+        assert_eq!(status("GET", "/run?day=3&input=/etc/passwd"), 400);
+        assert_eq!(status("GET", "/picture?day=3&input=sample"), 400);
+        assert_eq!(status("DELETE", "/run"), 405);
+        assert_eq!(status("GET", "/nothing"), 404);
+    }
+
+    #[test]
+    fn test_guarded() {
+        let mut dashboard = dashboard();
+        dashboard.upload_limits.max_iterations = Some(10);
+        dashboard.upload_timeout = Duration::from_millis(100);
+        let upload = request("POST", "/run?day=6", "");
+        assert_eq!(dashboard.guarded(&upload, || 7), Ok(7));
+        let response = dashboard.guarded(&upload, || panic!("a bug")).unwrap_err();
+        assert_eq!(response.status, 500);
+        let response = dashboard
+            .guarded(&upload, || thread::sleep(Duration::from_secs(1)))
+            .unwrap_err();
+        assert_eq!(response.status, 503);
+        // a named input is waited for
+        let named = request("GET", "/run?day=6", "");
+        assert_eq!(
+            dashboard.guarded(&named, || thread::sleep(Duration::from_millis(200))),
+            Ok(())
+        );
+
+        // the thread given up on still counts, until it ends
+        dashboard.max_uploads = 1;
+        let response = dashboard
+            .guarded(&upload, || thread::sleep(Duration::from_millis(500)))
+            .unwrap_err();
+        assert_eq!(response.status, 503);
+        let response = dashboard.guarded(&upload, || 7).unwrap_err();
+        assert!(
+            response.body.contains("running already"),
+            "{}",
+            response.body
+        );
+        assert_eq!(dashboard.guarded(&named, || 7), Ok(7));
+        thread::sleep(Duration::from_millis(800));
+        assert_eq!(dashboard.guarded(&upload, || 7), Ok(7));
+        dashboard.max_uploads = MAX_UPLOADS;
+
+        let sample = std::fs::read_to_string(dashboard.locator.named(6, Named::Sample)).unwrap();
+        let response = dashboard.handle(&request("POST", "/run?day=6&part=2", &sample));
+        assert_eq!(response.status, 503);
+        assert!(response.body.contains("10 iterations"), "{}", response.body);
+        let response = dashboard.handle(&request("GET", "/run?day=6&part=2&input=sample", ""));
+        assert_eq!(response.status, 200);
+
+        let mut written = Vec::new();
+        response.write(&mut written).unwrap();
+        assert!(written.starts_with(b"HTTP/1.1 200 OK\r\n"));
+        let mut written = Vec::new();
+        Response::error(500, "").write(&mut written).unwrap();
+        assert!(written.starts_with(b"HTTP/1.1 500 Internal Server Error\r\n"));
+    }
+
+    #[test]
+    fn test_serve() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve_on(listener, Locator::new(workspace_dir())));
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(b"GET /run?day=3&part=1&input=sample HTTP/1.1\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+        assert!(response.ends_with("]}]}"));
+    }
+}
@@ -0,0 +1,216 @@
+//! Shrinks an input that makes a day fail down to a small one failing the same way.
+//!
+//! The input is cut in pieces the day can still parse: lines for the lists, rows and columns
+//! for the grids, the blank-line separated blocks of day13 and the stones of day11. Pieces
+//! are dropped by delta debugging for as long as the failure stays.
+
+use std::{
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+};
+
+use anyhow::{bail, Result};
+use common::parse;
+
+use crate::{cross, days};
+
+/// What has to keep happening on the shrunk input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    /// solving the parts panics
+    Panic,
+    /// the implementations of the day disagree, see `cross`
+    Disagreement,
+}
+
+impl Failure {
+    /// whether `parts` of `day` still fail this way on `text`; a panic is caught, and printed
+    /// by the panic hook
+    pub fn reproduces(self, day: u8, parts: &[u8], text: &str) -> bool {
+        match self {
+            Failure::Panic => {
+                panic::catch_unwind(AssertUnwindSafe(|| days::solve_text(day, parts, text)))
+                    .is_err()
+            }
+            Failure::Disagreement => cross::cross_check_text(day, text)
+                .is_ok_and(|disagreements| !disagreements.is_empty()),
+        }
+    }
+}
+
+impl FromStr for Failure {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "panic" => Ok(Failure::Panic),
+            "disagreement" => Ok(Failure::Disagreement),
+            _ => Err(format!("{:?} is not panic or disagreement", s)),
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panic => write!(f, "panic"),
+            Failure::Disagreement => write!(f, "disagreement"),
+        }
+    }
+}
+
+/// How the input of a day can be cut without breaking its format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+    Lines,
+    /// rows and columns of the same length
+    Grid,
+    /// groups of lines between blank lines
+    Blocks,
+    /// one line of numbers
+    Words,
+}
+
+impl Shape {
+    fn of(day: u8) -> Self {
+        match day {
+            4 | 6 | 10 | 12 => Shape::Grid,
+            13 => Shape::Blocks,
+            11 => Shape::Words,
+            _ => Shape::Lines,
+        }
+    }
+}
+
+/// the smallest input found from `text` on which `fails` still holds; it has to hold on
+/// `text` itself
+pub fn shrink(day: u8, text: &str, mut fails: impl FnMut(&str) -> bool) -> Result<String> {
+    if !fails(text) {
+        bail!("the input does not fail to begin with");
+    }
+    let lines: Vec<&str> = text.lines().collect();
+    let shrunk = match Shape::of(day) {
+        Shape::Lines => lines_text(&ddmin(lines, &mut |lines| fails(&lines_text(lines)))),
+        Shape::Blocks => {
+            // the lines of a block, without their line endings, CRLF or not
+            let blocks: Vec<String> = parse::blocks(text)
+                .iter()
+                .map(|block| {
+                    let lines: Vec<&str> = block.iter().map(|cursor| cursor.rest()).collect();
+                    lines.join("\n")
+                })
+                .collect();
+            let blocks_text = |blocks: &[String]| format!("{}\n", blocks.join("\n\n"));
+            let blocks = ddmin(blocks, &mut |blocks| fails(&blocks_text(blocks)));
+            blocks_text(&blocks)
+        }
+        Shape::Grid => shrink_grid(lines, &mut fails),
+        Shape::Words => {
+            let words_text = |words: &[&str]| format!("{}\n", words.join(" "));
+            let words = ddmin(text.split_whitespace().collect(), &mut |words| {
+                fails(&words_text(words))
+            });
+            words_text(&words)
+        }
+    };
+    Ok(shrunk)
+}
+
+fn lines_text(lines: &[&str]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// drop rows, then columns, then rows again until neither can go
+fn shrink_grid(mut rows: Vec<&str>, fails: &mut impl FnMut(&str) -> bool) -> String {
+    let mut columns: Vec<usize> =
+        (0..rows.iter().map(|row| row.len()).max().unwrap_or(0)).collect();
+    // only the kept columns of every row, the grids are ASCII
+    let grid_text = |rows: &[&str], columns: &[usize]| -> String {
+        rows.iter()
+            .map(|row| {
+                let row = row.as_bytes();
+                let mut line: String = columns
+                    .iter()
+                    .filter_map(|col| row.get(*col).map(|c| char::from(*c)))
+                    .collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    };
+    loop {
+        let size = (rows.len(), columns.len());
+        rows = ddmin(rows, &mut |rows| fails(&grid_text(rows, &columns)));
+        columns = ddmin(columns, &mut |columns| fails(&grid_text(&rows, columns)));
+        if (rows.len(), columns.len()) == size {
+            return grid_text(&rows, &columns);
+        }
+    }
+}
+
+/// the shortest sublist of `units` found on which `fails` still holds: drop one of `n`
+/// chunks at a time, and cut the chunks finer when none of them can go
+fn ddmin<T: Clone>(mut units: Vec<T>, fails: &mut impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut n = 2;
+    while units.len() >= 2 {
+        let chunk = units.len().div_ceil(n);
+        let mut reduced = false;
+        for start in (0..units.len()).step_by(chunk) {
+            let mut rest = units[..start].to_vec();
+            rest.extend_from_slice(&units[(start + chunk).min(units.len())..]);
+            if fails(&rest) {
+                units = rest;
+                n = (n - 1).max(2);
+                reduced = true;
+                break;
+            }
+        }
+        if !reduced {
+            if n >= units.len() {
+                break;
+            }
+            n = (n * 2).min(units.len());
+        }
+    }
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ddmin() {
+        let mut runs = 0;
+        let units = ddmin((0..100).collect(), &mut |units: &[i32]| {
+            runs += 1;
+            units.contains(&17) && units.contains(&42)
+        });
+        assert_eq!(units, vec![17, 42]);
+        assert!(runs < 100, "{} runs", runs);
+    }
+
+    #[test]
+    fn test_shrink() {
+        let lines = "1 2\n3 4\n5 6\n7 8\n";
+        let shrunk = shrink(2, lines, |text| text.contains('5')).unwrap();
+        assert_eq!(shrunk, "5 6\n");
+
+        let grid = "....\n.#..\n..X.\n....\n";
+        let shrunk = shrink(6, grid, |text| text.contains('#') && text.contains('X')).unwrap();
+        assert_eq!(shrunk, "#.\n.X\n");
+
+        let blocks = "A\n1\n\nB\n2\n\nC\n3\n";
+        let shrunk = shrink(13, blocks, |text| text.contains("B\n2")).unwrap();
+        assert_eq!(shrunk, "B\n2\n");
+        // the blocks of a CRLF input are split too
+        let shrunk = shrink(13, &blocks.replace('\n', "\r\n"), |text| text.contains('B')).unwrap();
+        assert_eq!(shrunk, "B\n2\n");
+
+        let shrunk = shrink(11, "125 17 0 5\n", |text| text.contains("17")).unwrap();
+        assert_eq!(shrunk, "17\n");
+
+        assert!(shrink(2, lines, |_| false).is_err());
+    }
+}
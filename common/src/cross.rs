@@ -0,0 +1,65 @@
+use std::fmt::{self, Debug, Display};
+
+use crate::Solution;
+
+/// Where a reference implementation and the fast one disagree, on one case of an input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    /// which pair of implementations, like `blink` against `blink_alter`
+    pub check: &'static str,
+    /// what they ran on, like a line or a position of the input
+    pub case: String,
+    pub reference: String,
+    pub fast: String,
+}
+
+impl Disagreement {
+    /// a disagreement when `reference` and `fast` differ, nothing when they agree
+    pub fn compare<T: Debug + PartialEq>(
+        check: &'static str,
+        case: impl Display,
+        reference: T,
+        fast: T,
+    ) -> Option<Self> {
+        (reference != fast).then(|| Self {
+            check,
+            case: case.to_string(),
+            reference: format!("{:?}", reference),
+            fast: format!("{:?}", fast),
+        })
+    }
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} on {}: the reference gives {}, the fast one {}",
+            self.check, self.case, self.reference, self.fast
+        )
+    }
+}
+
+/// A day keeping a slow, plainly right implementation next to the fast one it answers with.
+///
+/// `cross_check` runs both over the parsed input, usually a generated one, so that a bug in
+/// the fast one shows up as a disagreement rather than as a wrong answer.
+pub trait CrossCheck: Solution {
+    /// every disagreement between the implementations on this input, none when they agree
+    fn cross_check(&self) -> Vec<Disagreement>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(Disagreement::compare("add", "line 1", 2, 2), None);
+        let disagreement = Disagreement::compare("add", "line 1", 2, 3).unwrap();
+        assert_eq!(
+            disagreement.to_string(),
+            "add on line 1: the reference gives 2, the fast one 3"
+        );
+    }
+}
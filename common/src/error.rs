@@ -0,0 +1,111 @@
+use std::{
+    fmt::{self, Display},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Why and where a puzzle input could not be parsed, lines and columns count from 1.
+///
+/// `parse` only sees the text, the file is filled in by `Solution::load`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// `found` is shown as it is, quote it when it is a piece of the input
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// `found` has to be a slice of `line`, the column is where it sits in the line
+    pub fn in_line(line_no: usize, line: &str, found: &str, expected: impl Into<String>) -> Self {
+        let offset = (found.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        let found = if found.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("{:?}", found)
+        };
+        Self::new(line_no, offset.min(line.len()) + 1, expected, found)
+    }
+
+    /// the line stops before something that should follow
+    pub fn end_of_line(line_no: usize, line: &str, expected: impl Into<String>) -> Self {
+        Self::new(line_no, line.len() + 1, expected, "end of line")
+    }
+
+    /// the input stops before something that should follow
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        let line = input.lines().count().max(1);
+        let column = input.lines().last().map_or(0, str::len) + 1;
+        Self::new(line, column, expected, "end of input")
+    }
+
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// parse `field`, a slice of `line`, into a number (or anything else `FromStr`)
+pub fn parse_field<T: FromStr>(
+    line_no: usize,
+    line: &str,
+    field: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    field
+        .parse()
+        .map_err(|_| ParseError::in_line(line_no, line, field, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let line = "3   x4";
+        let field = line.split_whitespace().nth(1).unwrap();
+        let error = parse_field::<u32>(2, line, field, "a number").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(
+            error.with_file("day1/data.txt").to_string(),
+            "day1/data.txt:2:5: expected a number, found \"x4\""
+        );
+        assert_eq!(
+            ParseError::end_of_input("1 2\n3", "a number").to_string(),
+            "2:2: expected a number, found end of input"
+        );
+    }
+}
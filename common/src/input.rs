@@ -0,0 +1,136 @@
+//! Where the puzzle inputs are, wherever the program is started from.
+//!
+//! Inputs live in a `dayN` directory per day, under the workspace by default or under
+//! `AOC_INPUT_DIR` when it is set. An input is asked for by name (`real`, `sample`, `sample2`)
+//! or by an explicit path, which is used as it is.
+
+use std::{
+    env,
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// the environment variable pointing to a directory of `dayN` directories
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The inputs every day has, or may have
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Named {
+    Real,
+    Sample,
+    /// a second example, when part two has its own
+    Sample2,
+}
+
+impl Named {
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Named::Real => "data.txt",
+            Named::Sample => "data_test.txt",
+            Named::Sample2 => "data_test2.txt",
+        }
+    }
+
+    /// the named input `path` is, from its file name
+    pub fn of_path(path: &Path) -> Option<Self> {
+        [Named::Real, Named::Sample, Named::Sample2]
+            .into_iter()
+            .find(|name| path.file_name() == Some(name.file_name().as_ref()))
+    }
+}
+
+impl FromStr for Named {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "real" => Ok(Named::Real),
+            "sample" => Ok(Named::Sample),
+            "sample2" => Ok(Named::Sample2),
+            _ => Err(format!("no input is named {:?}", s)),
+        }
+    }
+}
+
+impl Display for Named {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Named::Real => "real",
+            Named::Sample => "sample",
+            Named::Sample2 => "sample2",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The workspace this crate was built in, found from `CARGO_MANIFEST_DIR` and not from the
+/// current directory
+pub fn workspace_dir() -> PathBuf {
+    let common = Path::new(env!("CARGO_MANIFEST_DIR"));
+    common.parent().unwrap_or(common).to_path_buf()
+}
+
+/// Finds the inputs of the days under one directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locator {
+    dir: PathBuf,
+}
+
+impl Locator {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// `AOC_INPUT_DIR` when it is set, the workspace otherwise
+    pub fn from_env() -> Self {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) if !dir.is_empty() => Self::new(dir),
+            _ => Self::new(workspace_dir()),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{}", day))
+    }
+
+    pub fn named(&self, day: u8, name: Named) -> PathBuf {
+        self.day_dir(day).join(name.file_name())
+    }
+
+    /// `input` is an input name, or a path used as it is; `-` stays `-`, the runners read it
+    /// as stdin
+    pub fn locate(&self, day: u8, input: &str) -> PathBuf {
+        match input.parse() {
+            Ok(name) => self.named(day, name),
+            Err(_) => PathBuf::from(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let locator = Locator::new("/inputs");
+        assert_eq!(
+            locator.locate(3, "sample2"),
+            Path::new("/inputs/day3/data_test2.txt")
+        );
+        assert_eq!(locator.locate(3, "my.txt"), Path::new("my.txt"));
+        assert_eq!(
+            Named::of_path(&locator.locate(3, "sample")),
+            Some(Named::Sample)
+        );
+        assert_eq!(Named::of_path(Path::new("day3/data_test.md")), None);
+        assert!(Locator::new(workspace_dir())
+            .named(1, Named::Real)
+            .is_file());
+    }
+}
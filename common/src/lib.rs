@@ -0,0 +1,38 @@
+mod cross;
+mod error;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod plugin;
+mod progress;
+mod rng;
+mod solution;
+
+pub use cross::{CrossCheck, Disagreement};
+pub use error::{parse_field, ParseError};
+pub use progress::{Cancelled, Progress};
+pub use rng::Rng;
+pub use solution::Solution;
+
+/// a file next to the `Cargo.toml` of the crate using it, so tests find their inputs
+/// wherever `cargo test` is run from
+#[macro_export]
+macro_rules! manifest_path {
+    ($file:expr) => {
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($file)
+    };
+}
+
+/// `impl FromStr` through `Solution::parse`, so `"...".parse::<T>()` works on the puzzle types
+#[macro_export]
+macro_rules! impl_from_str {
+    ($type:ty) => {
+        impl std::str::FromStr for $type {
+            type Err = anyhow::Error;
+
+            fn from_str(input: &str) -> anyhow::Result<Self> {
+                <Self as $crate::Solution>::parse(input)
+            }
+        }
+    };
+}
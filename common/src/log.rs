@@ -0,0 +1,163 @@
+//! Leveled messages on stderr, quiet unless the runner asks for more.
+//!
+//! The days log through the `error!`, `info!`, `debug!` and `trace!` macros; which ones are
+//! shown is set once by `set_filter`, for every day at once or day by day.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        RwLock,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error = 1,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "error" => Ok(Level::Error),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "unknown log level {:?}, expected error, info, debug or trace",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "error",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The level of every crate, and the crates logging more or less than that
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    pub default: Level,
+    pub crates: Vec<(String, Level)>,
+}
+
+impl Filter {
+    pub const QUIET: Filter = Filter {
+        default: Level::Error,
+        crates: Vec::new(),
+    };
+
+    /// `info` for every crate, `day12=trace` for one, or both joined by commas like
+    /// `info,day12=trace`
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut ret = Filter::QUIET;
+        for item in spec.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            match item.split_once('=') {
+                Some((name, level)) => ret.crates.push((name.to_string(), level.parse()?)),
+                None => ret.default = item.parse()?,
+            }
+        }
+        Ok(ret)
+    }
+
+    /// `target` is a module path, only its crate counts
+    pub fn level(&self, target: &str) -> Level {
+        let name = target.split("::").next().unwrap_or(target);
+        self.crates
+            .iter()
+            .rev()
+            .find(|(crate_name, _)| crate_name == name)
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn max(&self) -> Level {
+        self.crates
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Level::max)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter::QUIET);
+/// the most verbose level of the filter, so the messages nobody wants cost only a load
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Error as u8);
+
+pub fn set_filter(filter: Filter) {
+    MAX_LEVEL.store(filter.max() as u8, Ordering::Relaxed);
+    *FILTER.write().unwrap_or_else(|e| e.into_inner()) = filter;
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+        && level
+            <= FILTER
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .level(target)
+}
+
+/// what the macros call, `target` is the module path of the caller
+pub fn log(level: Level, target: &str, args: fmt::Arguments<'_>) {
+    if enabled(level, target) {
+        eprintln!("[{} {}] {}", level, target, args);
+    }
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => {
+        $crate::log::log($crate::log::Level::Error, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::log::log($crate::log::Level::Info, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::log::log($crate::log::Level::Debug, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::log::log($crate::log::Level::Trace, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter = Filter::parse("info, day12=trace").unwrap();
+        assert_eq!(filter.level("day12"), Level::Trace);
+        assert_eq!(filter.level("day12::generate"), Level::Trace);
+        assert_eq!(filter.level("day1"), Level::Info);
+        assert_eq!(filter.max(), Level::Trace);
+        assert_eq!(Filter::parse("").unwrap(), Filter::QUIET);
+        assert!(Filter::parse("day12=loud").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! Small pieces to parse a line from left to right, each one reporting where it failed.
+//!
+//! A `Cursor` walks through one line: `literal`, `unsigned` and `signed` take what they expect
+//! from the front, `separated` repeats one of them, and `end` checks nothing is left over.
+//! `lines` and `blocks` cut the whole input into lines with their numbers; grids are parsed by
+//! `Grid::parse_with` of the grid crate.
+
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// The part of one line that is still to be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor<'a> {
+    line_no: usize,
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line_no: usize, line: &'a str) -> Self {
+        Self {
+            line_no,
+            line,
+            rest: line,
+        }
+    }
+
+    pub fn line_no(&self) -> usize {
+        self.line_no
+    }
+
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// an error about what is left of the line
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        if self.rest.is_empty() {
+            ParseError::end_of_line(self.line_no, self.line, expected)
+        } else {
+            ParseError::in_line(self.line_no, self.line, self.rest, expected)
+        }
+    }
+
+    /// an error about `found`, a slice of the line
+    pub fn error_at(&self, found: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::in_line(self.line_no, self.line, found, expected)
+    }
+
+    /// take `literal` from the front
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        match self.rest.strip_prefix(literal) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(self.error(format!("{:?}", literal))),
+        }
+    }
+
+    /// take `literal` when it is at the front, and tell whether it was
+    pub fn optional(&mut self, literal: &str) -> bool {
+        self.literal(literal).is_ok()
+    }
+
+    /// skip the spaces at the front, there may be none
+    pub fn spaces(&mut self) {
+        self.rest = self.rest.trim_start_matches(' ');
+    }
+
+    /// take the digits at the front as a number
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.number(0, "a number")
+    }
+
+    /// take a number with an optional sign in front
+    pub fn signed<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let sign = usize::from(self.rest.starts_with(['-', '+']));
+        self.number(sign, "a signed number")
+    }
+
+    fn number<T: FromStr>(&mut self, sign: usize, expected: &str) -> Result<T, ParseError> {
+        let digits = self.rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign);
+        if digits == 0 {
+            return Err(self.error(expected));
+        }
+        let (number, rest) = self.rest.split_at(sign + digits);
+        let number = number
+            .parse()
+            .map_err(|_| self.error_at(number, format!("{} that fits", expected)))?;
+        self.rest = rest;
+        Ok(number)
+    }
+
+    /// one or more items parsed by `item`, with `separator` between them
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut ret = vec![item(self)?];
+        while self.optional(separator) {
+            ret.push(item(self)?);
+        }
+        Ok(ret)
+    }
+
+    /// nothing may be left of the line
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+/// a cursor on every line of `input`, lines count from 1
+pub fn lines(input: &str) -> impl Iterator<Item = Cursor<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| Cursor::new(idx + 1, line))
+}
+
+/// the groups of lines separated by one or more blank lines
+pub fn blocks(input: &str) -> Vec<Vec<Cursor<'_>>> {
+    let mut ret = vec![];
+    let mut block = vec![];
+    for cursor in lines(input) {
+        if cursor.rest().trim().is_empty() {
+            if !block.is_empty() {
+                ret.push(std::mem::take(&mut block));
+            }
+        } else {
+            block.push(cursor);
+        }
+    }
+    if !block.is_empty() {
+        ret.push(block);
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor() {
+        let mut cursor = Cursor::new(4, "p=0,-4 v=3,+3");
+        cursor.literal("p=").unwrap();
+        assert_eq!(cursor.separated(",", Cursor::signed), Ok(vec![0, -4]));
+        cursor.spaces();
+        cursor.literal("v=").unwrap();
+        assert_eq!(cursor.separated(",", Cursor::signed::<i8>), Ok(vec![3, 3]));
+        assert_eq!(cursor.end(), Ok(()));
+
+        let mut cursor = Cursor::new(1, "X=12a");
+        assert_eq!(
+            cursor.literal("Y").unwrap_err().to_string(),
+            "1:1: expected \"Y\", found \"X=12a\""
+        );
+        cursor.literal("X=").unwrap();
+        assert_eq!(cursor.unsigned::<u8>(), Ok(12));
+        assert_eq!(
+            cursor.end().unwrap_err().to_string(),
+            "1:5: expected end of line, found \"a\""
+        );
+        let mut cursor = Cursor::new(2, "300,");
+        assert_eq!(
+            cursor.unsigned::<u8>().unwrap_err().to_string(),
+            "2:1: expected a number that fits, found \"300\""
+        );
+        assert_eq!(
+            Cursor::new(2, "-").signed::<i32>().unwrap_err().to_string(),
+            "2:1: expected a signed number, found \"-\""
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks = blocks("a\nb\n\n\nc\n");
+        let lines: Vec<Vec<(usize, &str)>> = blocks
+            .iter()
+            .map(|block| block.iter().map(|c| (c.line_no(), c.rest())).collect())
+            .collect();
+        assert_eq!(lines, vec![vec![(1, "a"), (2, "b")], vec![(5, "c")]]);
+    }
+}
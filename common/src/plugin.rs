@@ -0,0 +1,137 @@
+//! The C ABI of a day built as a shared library, for the runner to load at run time.
+//!
+//! A plugin exports one function, `aoc_plugin_dayN`, returning a `Plugin`: the name of the
+//! solution and its parse, part 1 and part 2 behind plain C functions. Answers and errors are
+//! NUL-terminated strings owned by the plugin, handed back to `free_string` once read.
+//! `export_plugin!` does all of it for a `Solution`.
+
+use std::{
+    any::Any,
+    ffi::{c_char, c_void, CStr, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr, slice,
+};
+
+use anyhow::Result;
+
+use crate::Solution;
+
+/// bumped whenever `Plugin` changes, the runner refuses the plugins of another version
+pub const ABI_VERSION: u32 = 1;
+
+/// the exported function is this followed by the day, `aoc_plugin_day6`
+pub const SYMBOL_PREFIX: &str = "aoc_plugin_day";
+
+/// What a plugin exports, every pointer stays valid until the library is unloaded
+#[repr(C)]
+pub struct Plugin {
+    pub abi_version: u32,
+    pub day: u8,
+    pub name: *const c_char,
+    /// parse `len` bytes of UTF-8 into a solution, or return null with the error in `*error`
+    pub parse: unsafe extern "C" fn(*const u8, usize, *mut *mut c_char) -> *mut c_void,
+    /// the answer of part 1 of a parsed solution, or null with the error in `*error`
+    pub part_one: unsafe extern "C" fn(*const c_void, *mut *mut c_char) -> *mut c_char,
+    pub part_two: unsafe extern "C" fn(*const c_void, *mut *mut c_char) -> *mut c_char,
+    pub free: unsafe extern "C" fn(*mut c_void),
+    pub free_string: unsafe extern "C" fn(*mut c_char),
+}
+
+// the name is a static string, nothing in it changes
+unsafe impl Sync for Plugin {}
+
+impl Plugin {
+    pub const fn new<S: Solution>(day: u8, name: &'static CStr) -> Self {
+        Self {
+            abi_version: ABI_VERSION,
+            day,
+            name: name.as_ptr(),
+            parse: parse::<S>,
+            part_one: part::<S, 1>,
+            part_two: part::<S, 2>,
+            free: free::<S>,
+            free_string,
+        }
+    }
+}
+
+/// export `$type` as the plugin of `$day`, named after the crate; only for the crates built
+/// as a `cdylib`, see the `plugin` feature of the days
+#[macro_export]
+macro_rules! export_plugin {
+    ($day:literal, $type:ty) => {
+        #[export_name = concat!("aoc_plugin_day", $day)]
+        pub extern "C" fn aoc_plugin() -> *const $crate::plugin::Plugin {
+            static PLUGIN: $crate::plugin::Plugin = $crate::plugin::Plugin::new::<$type>(
+                $day,
+                match ::std::ffi::CStr::from_bytes_with_nul(
+                    concat!(env!("CARGO_PKG_NAME"), "\0").as_bytes(),
+                ) {
+                    Ok(name) => name,
+                    Err(_) => panic!("a crate name has no NUL"),
+                },
+            );
+            &PLUGIN
+        }
+    };
+}
+
+/// a C copy of `text`, a NUL in it would cut it short so it is dropped
+fn c_string(text: &str) -> *mut c_char {
+    CString::new(text.replace('\0', ""))
+        .unwrap_or_default()
+        .into_raw()
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    match panic.downcast::<String>() {
+        Ok(message) => format!("panicked: {}", message),
+        Err(panic) => match panic.downcast::<&str>() {
+            Ok(message) => format!("panicked: {}", message),
+            Err(_) => "panicked".to_string(),
+        },
+    }
+}
+
+/// a panic must not unwind into the runner, it becomes an error like any other
+unsafe extern "C" fn parse<S: Solution>(
+    input: *const u8,
+    len: usize,
+    error: *mut *mut c_char,
+) -> *mut c_void {
+    let input = slice::from_raw_parts(input, len);
+    let parsed = panic::catch_unwind(|| -> Result<S> { S::parse(std::str::from_utf8(input)?) });
+    match parsed.map_err(panic_message) {
+        Ok(Ok(solution)) => Box::into_raw(Box::new(solution)).cast(),
+        Ok(Err(e)) => {
+            *error = c_string(&format!("{:#}", e));
+            ptr::null_mut()
+        }
+        Err(message) => {
+            *error = c_string(&message);
+            ptr::null_mut()
+        }
+    }
+}
+
+unsafe extern "C" fn part<S: Solution, const PART: u8>(
+    solution: *const c_void,
+    error: *mut *mut c_char,
+) -> *mut c_char {
+    let solution = &*solution.cast::<S>();
+    match panic::catch_unwind(AssertUnwindSafe(|| solution.solve(PART))) {
+        Ok(answer) => c_string(&answer),
+        Err(panic) => {
+            *error = c_string(&panic_message(panic));
+            ptr::null_mut()
+        }
+    }
+}
+
+unsafe extern "C" fn free<S: Solution>(solution: *mut c_void) {
+    drop(Box::from_raw(solution.cast::<S>()));
+}
+
+unsafe extern "C" fn free_string(text: *mut c_char) {
+    drop(CString::from_raw(text));
+}
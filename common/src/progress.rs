@@ -0,0 +1,109 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+/// Why a solver gave up before its answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cancelled {
+    /// the deadline passed, after that many iterations
+    Deadline(u64),
+    MaxIterations(u64),
+}
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cancelled::Deadline(iterations) => {
+                write!(f, "timed out after {} iterations", iterations)
+            }
+            Cancelled::MaxIterations(iterations) => {
+                write!(f, "gave up after the maximum of {} iterations", iterations)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// gets the iterations done so far, and how many there are in total when it is known
+type Callback<'a> = Box<dyn FnMut(u64, Option<u64>) + 'a>;
+
+/// What a long solver reports to, and asks whether it may go on.
+///
+/// The solver calls `tick` once per iteration, with how far it is when it knows; the default
+/// handle has no callback and no limit, so it never cancels.
+#[derive(Default)]
+pub struct Progress<'a> {
+    callback: Option<Callback<'a>>,
+    deadline: Option<Instant>,
+    max_iterations: Option<u64>,
+    iterations: u64,
+}
+
+impl<'a> Progress<'a> {
+    /// `callback` gets the iterations done so far and, when the solver knows it, how many
+    /// there are in total
+    pub fn with_callback(mut self, callback: impl FnMut(u64, Option<u64>) + 'a) -> Self {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: u64) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    /// one more iteration, out of `total` when it is known
+    pub fn tick(&mut self, total: Option<u64>) -> Result<(), Cancelled> {
+        self.iterations += 1;
+        if let Some(callback) = &mut self.callback {
+            callback(self.iterations, total);
+        }
+        if self.max_iterations.is_some_and(|max| self.iterations > max) {
+            return Err(Cancelled::MaxIterations(self.iterations - 1));
+        }
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Err(Cancelled::Deadline(self.iterations));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits() {
+        let mut seen = Vec::new();
+        let mut progress = Progress::default()
+            .with_callback(|done, total| seen.push((done, total)))
+            .with_max_iterations(2);
+        assert_eq!(progress.tick(Some(5)), Ok(()));
+        assert_eq!(progress.tick(Some(5)), Ok(()));
+        assert_eq!(progress.tick(Some(5)), Err(Cancelled::MaxIterations(2)));
+        drop(progress);
+        assert_eq!(seen, vec![(1, Some(5)), (2, Some(5)), (3, Some(5))]);
+
+        let mut progress = Progress::default().with_timeout(Duration::ZERO);
+        assert_eq!(progress.tick(None), Err(Cancelled::Deadline(1)));
+        assert_eq!(Progress::default().tick(None), Ok(()));
+    }
+}
@@ -0,0 +1,118 @@
+use std::{
+    fmt::{Debug, Display},
+    fs,
+    io::BufRead,
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+
+use crate::{input::Named, Cancelled, ParseError, Progress};
+
+/// The lifecycle shared by every day: parse the puzzle input once, then answer both parts.
+///
+/// Both parts take `&self`, so they can be called in any order and any number of times.
+pub trait Solution: Sized {
+    type Answer: Display + Debug + PartialEq;
+
+    fn parse(input: &str) -> Result<Self>;
+
+    /// parse whatever the reader gives until its end, such as stdin
+    fn from_reader(mut reader: impl BufRead) -> Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&input)
+    }
+
+    /// parse knowing which named input `input` is, `None` when it is none of them; only a day
+    /// whose sample differs from its real input by more than the text looks at the name
+    fn parse_named(input: &str, name: Option<Named>) -> Result<Self> {
+        let _ = name;
+        Self::parse(input)
+    }
+
+    /// read and parse an input file, a `ParseError` gets the file name attached
+    fn load(path: &Path) -> Result<Self> {
+        let input =
+            fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))?;
+        Self::parse_named(&input, Named::of_path(path)).map_err(|mut e| {
+            if let Some(parse_error) = e.downcast_mut::<ParseError>() {
+                parse_error.file = Some(path.to_path_buf());
+            }
+            e
+        })
+    }
+
+    fn part_one(&self) -> Self::Answer;
+
+    fn part_two(&self) -> Self::Answer;
+
+    /// `part_one` reporting to `progress`, and giving up when it says so;
+    /// only the slow days do more than call `part_one`
+    fn part_one_with(&self, progress: &mut Progress<'_>) -> Result<Self::Answer, Cancelled> {
+        let _ = progress;
+        Ok(self.part_one())
+    }
+
+    /// `part_two` reporting to `progress`, see `part_one_with`
+    fn part_two_with(&self, progress: &mut Progress<'_>) -> Result<Self::Answer, Cancelled> {
+        let _ = progress;
+        Ok(self.part_two())
+    }
+
+    /// answer one part as text; the callers check the part is 1 or 2 before any work is done
+    fn solve(&self, part: u8) -> String {
+        match part {
+            1 => self.part_one().to_string(),
+            2 => self.part_two().to_string(),
+            _ => unreachable!("there is no part {}", part),
+        }
+    }
+
+    /// like `solve`, reporting to `progress`
+    fn solve_with(&self, part: u8, progress: &mut Progress<'_>) -> Result<String, Cancelled> {
+        match part {
+            1 => Ok(self.part_one_with(progress)?.to_string()),
+            2 => Ok(self.part_two_with(progress)?.to_string()),
+            _ => unreachable!("there is no part {}", part),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Parts;
+
+    impl Solution for Parts {
+        type Answer = u8;
+
+        fn parse(_: &str) -> Result<Self> {
+            Ok(Parts)
+        }
+
+        fn part_one(&self) -> u8 {
+            1
+        }
+
+        fn part_two(&self) -> u8 {
+            2
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Parts.solve(1), "1");
+        assert_eq!(
+            Parts.solve_with(2, &mut Progress::default()),
+            Ok("2".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "there is no part 7")]
+    fn test_no_part() {
+        Parts.solve(7);
+    }
+}
@@ -0,0 +1,20 @@
+use common::Rng;
+
+/// `size` lines of two 5 digit location ids, like the real input;
+/// the right list often repeats an id of the left one, so the similarity is not always 0
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut lefts = Vec::with_capacity(size);
+    let mut ret = String::new();
+    for _ in 0..size {
+        let left = rng.between(10000, 99999);
+        lefts.push(left);
+        let right = if rng.chance(30) {
+            *rng.pick(&lefts)
+        } else {
+            rng.between(10000, 99999)
+        };
+        ret.push_str(&format!("{}   {}\n", left, right));
+    }
+    ret
+}
@@ -1,34 +1,18 @@
+pub mod generate;
+
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 
-use anyhow::{anyhow, Result};
-struct List {
+use anyhow::Result;
+use common::{parse_field, ParseError, Solution};
+
+pub struct List {
     left: Vec<u32>,
     right: Vec<u32>,
 }
 impl List {
-    fn init(file_path: PathBuf) -> Result<Self> {
-        let file = File::open(file_path)?;
-        let reader = BufReader::new(file);
-        let mut left = Vec::new();
-        let mut right = Vec::new();
-        for line in reader.lines() {
-            let line_str = line?;
-            let parts: Vec<&str> = line_str.split_whitespace().collect();
-            if parts.len() == 2 {
-                if let (Ok(num1), Ok(num2)) = (parts[0].parse::<u32>(), parts[1].parse::<u32>()) {
-                    left.push(num1);
-                    right.push(num2);
-                } else {
-                    return Err(anyhow!("Read data error"));
-                }
-            } else {
-                return Err(anyhow!("Read data error"));
-            }
-        }
-        Ok(Self { left, right })
+    pub fn init(file_path: PathBuf) -> Result<Self> {
+        Self::load(&file_path)
     }
 
     fn sort(&mut self) {
@@ -36,42 +20,104 @@ impl List {
         self.right.sort();
     }
 
-    fn cal_distance(&self) -> u32 {
+    fn cal_distance(&self) -> u128 {
         let mut ret = 0;
         for (num1, num2) in self.left.iter().zip(self.right.iter()) {
-            match num1.cmp(num2) {
-                std::cmp::Ordering::Greater | std::cmp::Ordering::Equal => ret += num1 - num2,
-                std::cmp::Ordering::Less => ret += num2 - num1,
-            }
+            ret += num1.abs_diff(*num2) as u128;
         }
         ret
     }
 
-    fn cal_similarity(&self) -> u32 {
+    /// a u32 id times how often it shows up, added up for every line, can be past a u64,
+    /// so both parts sum in a u128
+    fn cal_similarity(&self) -> u128 {
         let mut map_of_right = HashMap::new();
         self.right.iter().for_each(|num| {
             *map_of_right.entry(num).or_insert(0) += 1;
         });
         let mut ret = 0;
         self.left.iter().for_each(|num| {
-            ret += num * map_of_right.get(num).unwrap_or(&0);
+            ret += *num as u128 * map_of_right.get(num).unwrap_or(&0);
         });
         ret
     }
 }
 
+impl Solution for List {
+    type Answer = u128;
+
+    /// the lists are sorted right after loading, since the distance pairs up the sorted numbers
+    fn parse(input: &str) -> Result<Self> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for (idx, line_str) in input.lines().enumerate() {
+            let mut parts = line_str.split_whitespace();
+            for list in [&mut left, &mut right] {
+                let part = parts.next().ok_or_else(|| {
+                    ParseError::end_of_line(idx + 1, line_str, "two location ids")
+                })?;
+                list.push(parse_field(idx + 1, line_str, part, "a location id")?);
+            }
+            if let Some(extra) = parts.next() {
+                return Err(ParseError::in_line(idx + 1, line_str, extra, "end of line").into());
+            }
+        }
+        if left.is_empty() {
+            return Err(ParseError::end_of_input(input, "two location ids").into());
+        }
+        let mut list = Self { left, right };
+        list.sort();
+        Ok(list)
+    }
+
+    fn part_one(&self) -> u128 {
+        self.cal_distance()
+    }
+
+    fn part_two(&self) -> u128 {
+        self.cal_similarity()
+    }
+}
+
+common::impl_from_str!(List);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(1, List);
+
 #[cfg(test)]
 mod tests {
-
     use super::*;
+    use common::manifest_path;
+
+    #[test]
+    fn test_sample() {
+        let list = List::init(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(list.part_one(), 11);
+        assert_eq!(list.part_two(), 31);
+    }
+
+    #[test]
+    fn test_inline() {
+        let list =
+            List::from_reader("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n".as_bytes()).unwrap();
+        assert_eq!(list.part_one(), 11);
+        assert!("3   4\n4".parse::<List>().is_err());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let list: List = "3000000000   1\n3000000000   1\n".parse().unwrap();
+        assert_eq!(list.part_one(), 2 * 2999999999);
+        let list: List = "4294967295   4294967295\n4294967295   4294967295\n"
+            .parse()
+            .unwrap();
+        assert_eq!(list.part_two(), 4 * 4294967295);
+    }
 
     #[test]
     fn it_works() {
-        let mut file_path = std::env::current_dir().unwrap();
-        file_path.push("data.txt");
-        let mut list = List::init(file_path).unwrap();
-        list.sort();
-        println!("{:?}", list.cal_distance());
-        println!("{:?}", list.cal_similarity());
+        let list = List::init(manifest_path!("data.txt")).unwrap();
+        assert_eq!(list.part_one(), 2066446);
+        assert_eq!(list.part_two(), 24931009);
     }
 }
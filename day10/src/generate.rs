@@ -0,0 +1,23 @@
+use common::Rng;
+use grid::{Direction, Grid, Point};
+
+/// a `size` x `size` topographic map of random heights, with `size` hiking trails walked into it
+pub fn generate(size: usize, seed: u64) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let mut map = Grid::filled(size, size, '0');
+    for position in map.positions().collect::<Vec<_>>() {
+        map[position] = char::from(b'0' + rng.below(10) as u8);
+    }
+    for _ in 0..size {
+        let mut position = Point::from((rng.below(size), rng.below(size)));
+        for height in b'0'..=b'9' {
+            map[position] = char::from(height);
+            let next = position + *rng.pick(&Direction::ALL);
+            if map.contains_point(next) {
+                position = next;
+            }
+        }
+    }
+    map.to_string()
+}
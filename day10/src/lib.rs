@@ -0,0 +1,215 @@
+pub mod generate;
+
+use std::{collections::HashSet, path::PathBuf};
+
+use anyhow::Result;
+use common::{CrossCheck, Disagreement, ParseError, Solution};
+use grid::{Direction, Grid, Point};
+
+#[derive(Debug, Clone)]
+pub struct RoadMap {
+    map: Grid<i32>,
+    trail_heads: Vec<Point>,
+}
+
+impl RoadMap {
+    pub fn load_file(filename: PathBuf) -> Result<Self> {
+        Self::load(&filename)
+    }
+
+    fn cal_score(&self) -> i32 {
+        let mut ret = 0;
+        for trail_head in &self.trail_heads {
+            let mut visited_nine: HashSet<Point> = HashSet::new();
+            ret += self.dfs(*trail_head, -1, None, &mut visited_nine);
+        }
+        ret
+    }
+
+    fn cal_distinct_score(&self) -> i32 {
+        let mut ret = 0;
+        for trail_head in &self.trail_heads {
+            ret += self.dfs_distinct(*trail_head, -1, None);
+        }
+        ret
+    }
+
+    /// the directions to try from a point, never walking back to where the trail came from
+    fn next_directions(pre_direction: Option<Direction>) -> impl Iterator<Item = Direction> {
+        Direction::ALL
+            .into_iter()
+            .filter(move |direction| pre_direction != Some(direction.reverse()))
+    }
+
+    fn dfs(
+        &self,
+        position: Point,
+        prev_num: i32,
+        pre_direction: Option<Direction>,
+        visited_nine: &mut HashSet<Point>,
+    ) -> i32 {
+        let height = self.map[position];
+        // should check this first before check number is 9 or not
+        if height - prev_num != 1 {
+            0
+        } else if height == 9 {
+            // check the 9 has been visited or not
+            if visited_nine.insert(position) {
+                1
+            } else {
+                0
+            }
+        } else {
+            let mut ret = 0;
+            for direction in Self::next_directions(pre_direction) {
+                let next = position + direction;
+                if self.map.contains_point(next) {
+                    ret += self.dfs(next, height, Some(direction), visited_nine);
+                }
+            }
+            ret
+        }
+    }
+
+    fn dfs_distinct(
+        &self,
+        position: Point,
+        prev_num: i32,
+        pre_direction: Option<Direction>,
+    ) -> i32 {
+        let height = self.map[position];
+        if height - prev_num != 1 {
+            0
+        } else if height == 9 {
+            1
+        } else {
+            Self::next_directions(pre_direction)
+                .map(|direction| (position + direction, direction))
+                .filter(|(next, _)| self.map.contains_point(*next))
+                .map(|(next, direction)| self.dfs_distinct(next, height, Some(direction)))
+                .sum()
+        }
+    }
+
+    /// the heights on a trail from a 0 up to a 9, '.' everywhere else
+    pub fn picture(&self) -> Grid<char> {
+        // a cell is on a trail when a 9 is reachable going up from it, and a 0 going down
+        let mut to_nine = self.map.map(|height| *height == 9);
+        for height in (0..9).rev() {
+            for position in self.map.positions().collect::<Vec<_>>() {
+                if self.map[position] == height {
+                    to_nine[position] = self
+                        .map
+                        .neighbours4(position)
+                        .any(|next| self.map[next] == height + 1 && to_nine[next]);
+                }
+            }
+        }
+        let mut from_zero = self.map.map(|height| *height == 0);
+        for height in 1..=9 {
+            for position in self.map.positions().collect::<Vec<_>>() {
+                if self.map[position] == height {
+                    from_zero[position] = self
+                        .map
+                        .neighbours4(position)
+                        .any(|next| self.map[next] == height - 1 && from_zero[next]);
+                }
+            }
+        }
+        let mut map = self.map.map(|height| char::from(b'0' + *height as u8));
+        for position in self.map.positions().collect::<Vec<_>>() {
+            if !(to_nine[position] && from_zero[position]) {
+                map[position] = '.';
+            }
+        }
+        map
+    }
+
+    /// the reference for both walks: the 9 of every trail from `position` on, one per trail,
+    /// trying all four directions every time
+    fn trail_ends(&self, position: Point, ends: &mut Vec<Point>) {
+        let height = self.map[position];
+        if height == 9 {
+            ends.push(position);
+            return;
+        }
+        for direction in Direction::ALL {
+            let next = position + direction;
+            if self.map.contains_point(next) && self.map[next] == height + 1 {
+                self.trail_ends(next, ends);
+            }
+        }
+    }
+}
+
+impl Solution for RoadMap {
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let map = Grid::parse_with(input, |c| c.to_digit(10).map(|height| height as i32))
+            .map_err(ParseError::from)?;
+        let trail_heads = map
+            .iter()
+            .filter(|(_, height)| **height == 0)
+            .map(|(position, _)| Point::from(position))
+            .collect();
+        Ok(Self { map, trail_heads })
+    }
+
+    fn part_one(&self) -> i32 {
+        self.cal_score()
+    }
+
+    fn part_two(&self) -> i32 {
+        self.cal_distinct_score()
+    }
+}
+
+impl CrossCheck for RoadMap {
+    fn cross_check(&self) -> Vec<Disagreement> {
+        let mut ret = Vec::new();
+        for trail_head in &self.trail_heads {
+            let mut ends = Vec::new();
+            self.trail_ends(*trail_head, &mut ends);
+            let case = format!("the trail head at {:?}", trail_head);
+            ret.extend(Disagreement::compare(
+                "dfs_distinct against every trail",
+                &case,
+                ends.len() as i32,
+                self.dfs_distinct(*trail_head, -1, None),
+            ));
+            let distinct_ends: HashSet<Point> = ends.into_iter().collect();
+            ret.extend(Disagreement::compare(
+                "dfs against the ends of every trail",
+                &case,
+                distinct_ends.len() as i32,
+                self.dfs(*trail_head, -1, None, &mut HashSet::new()),
+            ));
+        }
+        ret
+    }
+}
+
+common::impl_from_str!(RoadMap);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(10, RoadMap);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::manifest_path;
+
+    #[test]
+    fn test_sample() {
+        let road_map = RoadMap::load_file(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(road_map.part_one(), 36);
+        assert_eq!(road_map.part_two(), 81);
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let road_map: RoadMap = generate::generate(30, 1).parse().unwrap();
+        assert_eq!(road_map.cross_check(), vec![]);
+    }
+}
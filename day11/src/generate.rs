@@ -0,0 +1,10 @@
+use common::Rng;
+
+/// `size` stones (at least 1) with numbers up to a million
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let stones: Vec<String> = (0..size.max(1))
+        .map(|_| rng.between(0, 1_000_000).to_string())
+        .collect();
+    format!("{}\n", stones.join(" "))
+}
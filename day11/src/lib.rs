@@ -0,0 +1,221 @@
+pub mod generate;
+
+use std::{collections::HashMap, path::PathBuf};
+
+use anyhow::Result;
+use common::{parse_field, Cancelled, CrossCheck, Disagreement, ParseError, Progress, Solution};
+
+pub struct StoneVec {
+    origin: Vec<String>,
+}
+
+impl StoneVec {
+    pub fn load_file(filepath: PathBuf) -> Result<Self> {
+        Self::load(&filepath)
+    }
+
+    fn blink(&self, blink_time: u16) -> usize {
+        let mut ret = 0;
+        for num in &self.origin {
+            let mut final_vec: Vec<String> = Vec::new();
+            Self::dfs(num.to_owned(), 0, blink_time, &mut final_vec);
+            ret += final_vec.len();
+            // println!("{:?} finished", num);
+        }
+
+        ret
+    }
+
+    /// one progress tick per stone that is not remembered yet
+    fn blink_alter(&self, blink_time: u16, progress: &mut Progress) -> Result<u64, Cancelled> {
+        let mut count = 0;
+        let mut memory_dict: HashMap<(String, u16), u64> = HashMap::new();
+        for num in &self.origin {
+            count +=
+                Self::dfs_with_memory(num.to_owned(), 0, blink_time, &mut memory_dict, progress)?;
+        }
+        Ok(count)
+    }
+
+    fn dfs(num: String, depth: u16, blink_time: u16, final_vec: &mut Vec<String>) {
+        if depth == blink_time {
+            final_vec.push(num);
+            return;
+        }
+        let num_len = num.len();
+        if num_len.is_multiple_of(2) {
+            let (left_num, right_num) = seperate_number(&num, num_len as i32);
+            Self::dfs(left_num, depth + 1, blink_time, final_vec);
+            Self::dfs(right_num, depth + 1, blink_time, final_vec);
+        } else if num_len == 1 && num.parse::<i32>().unwrap() == 0 {
+            Self::dfs(String::from("1"), depth + 1, blink_time, final_vec);
+        } else {
+            Self::dfs(multiply_2024(&num), depth + 1, blink_time, final_vec);
+        }
+    }
+
+    /// Use a HashMap<(number, depth), length> to memorize how many numbers will be created at this depth and deeper
+    /// To avoid repeated calucation of same number at same level
+    fn dfs_with_memory(
+        num: String,
+        depth: u16,
+        blink_time: u16,
+        memory_dict: &mut HashMap<(String, u16), u64>,
+        progress: &mut Progress,
+    ) -> Result<u64, Cancelled> {
+        // if the dict has record of the num at the same level
+        if memory_dict.contains_key(&(num.clone(), depth)) {
+            return Ok(*memory_dict.get(&(num.clone(), depth)).unwrap());
+        }
+        if depth == blink_time {
+            return Ok(1);
+        }
+        progress.tick(None)?;
+        let num_len = num.len();
+        let ret = if num_len.is_multiple_of(2) {
+            let (left_num, right_num) = seperate_number(&num, num_len as i32);
+            let left_result =
+                Self::dfs_with_memory(left_num, depth + 1, blink_time, memory_dict, progress)?;
+            let right_result =
+                Self::dfs_with_memory(right_num, depth + 1, blink_time, memory_dict, progress)?;
+            left_result + right_result
+        } else if num_len == 1 && num.parse::<i32>().unwrap() == 0 {
+            let one = String::from("1");
+            Self::dfs_with_memory(one, depth + 1, blink_time, memory_dict, progress)?
+        } else {
+            let num = multiply_2024(&num);
+            Self::dfs_with_memory(num, depth + 1, blink_time, memory_dict, progress)?
+        };
+        memory_dict.insert((num.clone(), depth), ret);
+        Ok(ret)
+    }
+}
+
+impl Solution for StoneVec {
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut stone_vec = Vec::new();
+        for (idx, line) in input.lines().enumerate() {
+            for num in line.split_whitespace() {
+                // the stones stay as text, but each of them has to be a number,
+                // written without leading zeros like the stones the blinks make
+                let num = parse_field::<u64>(idx + 1, line, num, "a number")?;
+                stone_vec.push(num.to_string());
+            }
+        }
+        if stone_vec.is_empty() {
+            return Err(ParseError::end_of_input(input, "a stone").into());
+        }
+        Ok(StoneVec { origin: stone_vec })
+    }
+
+    fn part_one(&self) -> u64 {
+        self.blink(25) as u64
+    }
+
+    fn part_two(&self) -> u64 {
+        // nothing cancels without a limit
+        self.part_two_with(&mut Progress::default()).unwrap()
+    }
+
+    fn part_two_with(&self, progress: &mut Progress) -> Result<u64, Cancelled> {
+        self.blink_alter(75, progress)
+    }
+}
+
+impl CrossCheck for StoneVec {
+    /// `blink` keeps every stone, so it only goes up to 15 blinks
+    fn cross_check(&self) -> Vec<Disagreement> {
+        (0..=15)
+            .filter_map(|blink_time| {
+                let memorized = self.blink_alter(blink_time, &mut Progress::default());
+                Disagreement::compare(
+                    "blink against blink_alter",
+                    format_args!("{} blinks", blink_time),
+                    Ok(self.blink(blink_time) as u64),
+                    memorized,
+                )
+            })
+            .collect()
+    }
+}
+
+common::impl_from_str!(StoneVec);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(11, StoneVec);
+
+fn seperate_number(num: &str, num_len: i32) -> (String, String) {
+    let half = (num_len as usize) / 2;
+    // the stones have no leading zeros, so neither has the left half
+    let left_num = &num[..half];
+    let mut right_num = &num.as_bytes()[half..(num_len as usize)];
+    let mut idx = 0;
+    while idx < right_num.len() && right_num[idx] - b'0' == 0 {
+        idx += 1;
+    }
+    if idx >= right_num.len() {
+        right_num = b"0";
+    } else {
+        right_num = &right_num[idx..right_num.len()];
+    }
+    (
+        left_num.to_string(),
+        String::from_utf8(right_num.to_vec()).unwrap(),
+    )
+}
+
+/// the stone times 2024, on the text once it does not fit a u64 any more
+fn multiply_2024(num: &str) -> String {
+    if let Some(product) = num.parse::<u64>().ok().and_then(|n| n.checked_mul(2024)) {
+        return product.to_string();
+    }
+    let mut digits = Vec::with_capacity(num.len() + 4);
+    let mut carry = 0;
+    for digit in num.bytes().rev() {
+        let product = (digit - b'0') as u32 * 2024 + carry;
+        digits.push(b'0' + (product % 10) as u8);
+        carry = product / 10;
+    }
+    while carry > 0 {
+        digits.push(b'0' + (carry % 10) as u8);
+        carry /= 10;
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::manifest_path;
+
+    #[test]
+    fn test_inline() {
+        let stones: StoneVec = "125 17".parse().unwrap();
+        assert_eq!(stones.blink(6), 22);
+    }
+
+    #[test]
+    fn test_multiply() {
+        assert_eq!(multiply_2024("17"), "34408");
+        assert_eq!(
+            multiply_2024("18446744073709551615"),
+            "37336210005188132468760"
+        );
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let stones: StoneVec = generate::generate(20, 3).parse().unwrap();
+        assert_eq!(stones.cross_check(), vec![]);
+    }
+
+    #[test]
+    fn test_sample() {
+        let stones = StoneVec::load_file(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(stones.part_one(), 55312);
+        assert_eq!(stones.part_two(), 65601038650482);
+    }
+}
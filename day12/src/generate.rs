@@ -0,0 +1,22 @@
+use common::Rng;
+use grid::Grid;
+
+/// a `size` x `size` garden; plots often copy the plant on their left or above, which grows regions
+pub fn generate(size: usize, seed: u64) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let plants: Vec<char> = (0..rng.between(2, 8))
+        .map(|_| char::from(b'A' + rng.below(26) as u8))
+        .collect();
+    let mut garden = Grid::filled(size, size, 'A');
+    for (row, col) in garden.positions().collect::<Vec<_>>() {
+        garden[(row, col)] = if row > 0 && rng.chance(35) {
+            garden[(row - 1, col)]
+        } else if col > 0 && rng.chance(50) {
+            garden[(row, col - 1)]
+        } else {
+            *rng.pick(&plants)
+        };
+    }
+    garden.to_string()
+}
@@ -0,0 +1,229 @@
+pub mod generate;
+
+use std::{collections::HashSet, path::PathBuf};
+
+use anyhow::Result;
+use common::{ParseError, Solution};
+use grid::Grid;
+
+pub struct AreaMap {
+    origin_map: Grid<u8>,
+    areas: Vec<Vec<(usize, usize)>>,
+}
+
+impl AreaMap {
+    pub fn load_data(filename: PathBuf) -> Result<Self> {
+        Self::load(&filename)
+    }
+
+    /// the plant of every plot, the regions are where the same letter touches
+    pub fn picture(&self) -> Grid<char> {
+        self.origin_map.map(|c| *c as char)
+    }
+
+    fn group_region_points_into_areas(&mut self) {
+        let mut visited: HashSet<(usize, usize)> = HashSet::new();
+        let mut areas = Vec::new();
+        for (position, c) in self.origin_map.iter() {
+            if visited.contains(&position) {
+                continue;
+            }
+            let mut group = Vec::new();
+            let mut neighbour_queue = vec![position];
+            while let Some(point) = neighbour_queue.pop() {
+                if visited.insert(point) {
+                    group.push(point);
+                    // push the neighbours of the point in the same region to neighbour_queue
+                    for neignbour in self.origin_map.neighbours4(point) {
+                        if !visited.contains(&neignbour) && self.origin_map[neignbour] == *c {
+                            neighbour_queue.push(neignbour);
+                        }
+                    }
+                }
+            }
+            common::debug!("char: {:?}, group: {:?}", *c as char, group);
+            areas.push(group);
+        }
+        self.areas = areas;
+    }
+
+    fn cal_perimeter_of_each_group(&self, group: &[(usize, usize)]) -> u64 {
+        let group_char = self.origin_map[group[0]];
+        let mut ret = 0;
+        for point in group.iter() {
+            // the perimeter contributed by this point is 4 - neignbours in the same region
+            let neighbours = self
+                .origin_map
+                .neighbours4(*point)
+                .filter(|neighbour| self.origin_map[*neighbour] == group_char)
+                .count() as u64;
+            ret += 4 - neighbours;
+        }
+        ret
+    }
+
+    fn cal_price(&self) -> u64 {
+        let mut ret = 0;
+        for group in self.areas.iter() {
+            let area_size = group.len();
+            let area_perimeter = self.cal_perimeter_of_each_group(group);
+            ret += area_perimeter * (area_size as u64);
+        }
+        ret
+    }
+
+    /// one side has 2 corners, and one corner will connect 2 sides; Which means we can count corners instead of count sides
+    ///
+    /// ```text
+    /// Identify corners:
+    ///      There are 2 kind of corners, external and internal.
+    ///      The external corner is neighboured by different region on at least 2 direction
+    ///          Example with 4 corners, each a is a corner
+    ///              aa
+    ///              aa
+    ///
+    ///          Example with 4 corners, each a are 2 corners
+    ///              a
+    ///              a
+    ///      The interal corner is neighboured by same region on at least 2 direction, but different region on one diagonal
+    ///          Example with 4 interal corners, and 8 external corners
+    ///             baab
+    ///            aaaaaa
+    ///            aaaaaa
+    ///             baab
+    ///
+    /// ```
+    fn cal_corners_of_each_group(&self, group: &[(usize, usize)]) -> u64 {
+        let mut external_corner = 0;
+        let mut internal_cornoer = 0;
+        let group_char = self.origin_map[group[0]];
+        for point in group {
+            let external = self.is_external_corner(point, group_char);
+            if external > 0 {
+                common::trace!("external {:?}", point);
+                external_corner += external;
+            }
+            let internal = self.is_internal_corner(point, group_char);
+            if internal > 0 {
+                common::trace!("internal {:?}", point);
+                internal_cornoer += internal;
+            }
+        }
+        common::debug!(
+            "corners: {:?} external, {:?} internal",
+            external_corner,
+            internal_cornoer
+        );
+        external_corner + internal_cornoer
+    }
+
+    /// the cell is in the region, anything outside the map is not
+    fn is_region(&self, row: isize, col: isize, region: u8) -> bool {
+        self.origin_map.get(row, col) == Some(&region)
+    }
+
+    /// the cell is on the map, but in another region
+    fn is_other_region(&self, row: isize, col: isize, region: u8) -> bool {
+        self.origin_map.get(row, col).is_some_and(|c| *c != region)
+    }
+
+    fn is_external_corner(&self, point: &(usize, usize), region: u8) -> u64 {
+        let (row, col) = (point.0 as isize, point.1 as isize);
+        let up = !self.is_region(row - 1, col, region);
+        let right = !self.is_region(row, col + 1, region);
+        let down = !self.is_region(row + 1, col, region);
+        let left = !self.is_region(row, col - 1, region);
+        let mut ret = 0;
+        if right && up {
+            ret += 1;
+        }
+        if left && up {
+            ret += 1;
+        }
+        if right && down {
+            ret += 1;
+        }
+        if left && down {
+            ret += 1;
+        }
+        ret
+    }
+
+    fn is_internal_corner(&self, point: &(usize, usize), region: u8) -> u64 {
+        let (row, col) = (point.0 as isize, point.1 as isize);
+        let up = self.is_region(row - 1, col, region);
+        let right = self.is_region(row, col + 1, region);
+        let down = self.is_region(row + 1, col, region);
+        let left = self.is_region(row, col - 1, region);
+        let up_left = self.is_other_region(row - 1, col - 1, region);
+        let up_right = self.is_other_region(row - 1, col + 1, region);
+        let down_left = self.is_other_region(row + 1, col - 1, region);
+        let down_right = self.is_other_region(row + 1, col + 1, region);
+        let mut ret = 0;
+        if right && up && up_right {
+            ret += 1;
+        }
+        if left && up && up_left {
+            ret += 1;
+        }
+        if right && down && down_right {
+            ret += 1;
+        }
+        if left && down && down_left {
+            ret += 1;
+        }
+        ret
+    }
+
+    fn cal_price_alter(&self) -> u64 {
+        let mut ret = 0;
+        for group in self.areas.iter() {
+            let area_size = group.len();
+            let area_corners = self.cal_corners_of_each_group(group);
+            ret += area_corners * (area_size as u64);
+        }
+        ret
+    }
+}
+
+impl Solution for AreaMap {
+    type Answer = u64;
+
+    /// the points are grouped into areas right after loading, both prices are based on the areas
+    fn parse(input: &str) -> Result<Self> {
+        let origin_map = Grid::parse_with(input, |c| c.is_ascii_alphabetic().then_some(c as u8))
+            .map_err(ParseError::from)?;
+        let mut area_map = Self {
+            origin_map,
+            areas: Vec::new(),
+        };
+        area_map.group_region_points_into_areas();
+        Ok(area_map)
+    }
+
+    fn part_one(&self) -> u64 {
+        self.cal_price()
+    }
+
+    fn part_two(&self) -> u64 {
+        self.cal_price_alter()
+    }
+}
+
+common::impl_from_str!(AreaMap);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(12, AreaMap);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::manifest_path;
+
+    #[test]
+    fn test_sample() {
+        let area_map = AreaMap::load_data(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(area_map.part_one(), 1930);
+        assert_eq!(area_map.part_two(), 1206);
+    }
+}
@@ -0,0 +1,21 @@
+use common::Rng;
+
+/// `size` claw machines; about half of the prizes can be won with at most 100 presses of each button
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut machines = Vec::new();
+    for _ in 0..size {
+        let a = (rng.between(10, 99), rng.between(10, 99));
+        let b = (rng.between(10, 99), rng.between(10, 99));
+        let (times_a, times_b) = (rng.between(0, 100), rng.between(0, 100));
+        let mut prize = (a.0 * times_a + b.0 * times_b, a.1 * times_a + b.1 * times_b);
+        if rng.chance(50) {
+            prize.0 += rng.between(1, 100);
+        }
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+    machines.join("\n")
+}
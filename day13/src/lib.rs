@@ -0,0 +1,214 @@
+pub mod generate;
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use common::{
+    parse::{self, Cursor},
+    ParseError, Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    button_a: (u64, u64),
+    button_b: (u64, u64),
+    prize: (u64, u64),
+}
+
+impl Machine {
+    /// the labels of the 3 lines describing one machine
+    const LABELS: [&'static str; 3] = ["Button A:", "Button B:", "Prize:"];
+
+    /// `lines` are the 3 lines of one machine
+    fn parse_machine(lines: &[Cursor]) -> Result<Self, ParseError> {
+        let button_a = Self::parse_from_line(lines[0], Self::LABELS[0])?;
+        let button_b = Self::parse_from_line(lines[1], Self::LABELS[1])?;
+        let prize = Self::parse_from_line(lines[2], Self::LABELS[2])?;
+        Ok(Machine {
+            button_a,
+            button_b,
+            prize,
+        })
+    }
+
+    /// `Button A: X+94, Y+34` for the buttons, `Prize: X=8400, Y=5400` for the prize; the
+    /// numbers fit in a u32, so that `solve_equation` cannot overflow
+    fn parse_from_line(mut line: Cursor, label: &str) -> Result<(u64, u64), ParseError> {
+        let sign = if label == Self::LABELS[2] { "=" } else { "+" };
+        line.literal(label)?;
+        line.literal(" X")?;
+        line.literal(sign)?;
+        let x: u32 = line.unsigned()?;
+        line.literal(", Y")?;
+        line.literal(sign)?;
+        let y: u32 = line.unsigned()?;
+        line.end()?;
+        Ok((x.into(), y.into()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Dataset(Vec<Machine>);
+
+impl Dataset {
+    pub fn load_data(filename: PathBuf) -> Result<Dataset> {
+        Self::load(&filename)
+    }
+
+    fn adjust_prize(&mut self) {
+        let mut idx = 0;
+        while idx < self.0.len() {
+            let machine = self.0.get_mut(idx).unwrap();
+            machine.prize.0 += 10000000000000;
+            machine.prize.1 += 10000000000000;
+            idx += 1;
+        }
+    }
+
+    /// a cost per machine fits in a u64, their sum over a big input may not
+    fn cal_cost(&self) -> u128 {
+        let mut ret = 0;
+        for machine in self.0.iter() {
+            if let Some((time_a, time_b)) =
+                Self::solve_equation(machine.button_a, machine.button_b, machine.prize)
+            {
+                ret += 3 * time_a as u128 + time_b as u128;
+            }
+        }
+        ret
+    }
+
+    /// the numbers are u32s, moved by 10^13 in part two, so their products fit in an i128;
+    /// a solution presses each button at most as many times as the prize is far
+    fn solve_equation(a: (u64, u64), b: (u64, u64), c: (u64, u64)) -> Option<(u64, u64)> {
+        let (a1, a2) = (a.0 as i128, a.1 as i128);
+        let (b1, b2) = (b.0 as i128, b.1 as i128);
+        let (c1, c2) = (c.0 as i128, c.1 as i128);
+        let denominate = a1 * b2 - a2 * b1;
+        if denominate == 0 {
+            return None;
+        }
+        let x = if (c1 * b2 - c2 * b1) % denominate == 0 {
+            (c1 * b2 - c2 * b1) / denominate
+        } else {
+            -1
+        };
+        let y = if (c2 * a1 - c1 * a2) % denominate == 0 {
+            (c2 * a1 - c1 * a2) / denominate
+        } else {
+            -1
+        };
+        if x >= 0 && y >= 0 {
+            Some((x as u64, y as u64))
+        } else {
+            None
+        }
+    }
+}
+
+impl Solution for Dataset {
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Dataset> {
+        let blocks = parse::blocks(input);
+        let mut machines = Vec::with_capacity(blocks.len());
+        for (idx, block) in blocks.iter().enumerate() {
+            if let Some(extra) = block.get(3) {
+                return Err(extra.error("an empty line between two machines").into());
+            }
+            if let Some(label) = Machine::LABELS.get(block.len()) {
+                // the machine is cut short, by the end of the input or by an empty line
+                let expected = format!("{:?}", label);
+                return Err(if idx + 1 == blocks.len() {
+                    ParseError::end_of_input(input, expected)
+                } else {
+                    ParseError::new(
+                        block[block.len() - 1].line_no() + 1,
+                        1,
+                        expected,
+                        "an empty line",
+                    )
+                }
+                .into());
+            }
+            machines.push(Machine::parse_machine(block)?);
+        }
+        if machines.is_empty() {
+            return Err(
+                ParseError::end_of_input(input, format!("{:?}", Machine::LABELS[0])).into(),
+            );
+        }
+        Ok(Dataset(machines))
+    }
+
+    fn part_one(&self) -> u128 {
+        self.cal_cost()
+    }
+
+    /// the prizes are far away in part two, so solve on an adjusted copy
+    fn part_two(&self) -> u128 {
+        let mut machines = self.clone();
+        machines.adjust_prize();
+        machines.cal_cost()
+    }
+}
+
+common::impl_from_str!(Dataset);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(13, Dataset);
+
+#[cfg(test)]
+mod test {
+    use crate::{Dataset, Machine};
+    use common::{manifest_path, parse::Cursor, Solution};
+
+    #[test]
+    fn test_split() {
+        let s = Cursor::new(1, "Prize: X=8400, Y=5400");
+        assert_eq!(Machine::parse_from_line(s, "Prize:"), Ok((8400, 5400)));
+        let s = Cursor::new(3, "Price: X+8400, Y+5400");
+        let error = Machine::parse_from_line(s, "Prize:").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "3:1: expected \"Prize:\", found \"Price: X+8400, Y+5400\""
+        );
+        let s = Cursor::new(2, "Button B: X+22, Y=67");
+        let error = Machine::parse_from_line(s, "Button B:").unwrap_err();
+        assert_eq!(error.to_string(), "2:18: expected \"+\", found \"=67\"");
+        let error = "Button A: X+94, Y+34\nButton B: X+22\n"
+            .parse::<Dataset>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2:15: expected \"Prize:\", found end of input"
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let dataset = Dataset::load_data(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(dataset.part_one(), 480);
+        assert_eq!(dataset.part_two(), 875318608908);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let error = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=99999999999, Y=5400"
+            .parse::<Dataset>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "3:10: expected a number that fits, found \"99999999999\""
+        );
+        // one press of each button reaches the prize, the products of part two are past a u64
+        let (m, prize) = (u32::MAX - 1, u32::MAX);
+        let machine = format!(
+            "Button A: X+{}, Y+1\nButton B: X+1, Y+{}\nPrize: X={}, Y={}\n",
+            m, m, prize, prize
+        );
+        let dataset: Dataset = format!("{}\n{}", machine, machine).parse().unwrap();
+        assert_eq!(dataset.part_one(), 2 * (3 + 1));
+        assert_eq!(dataset.part_two(), 0);
+    }
+}
@@ -0,0 +1,17 @@
+use common::Rng;
+
+/// `size` robots in the real 101 x 103 room
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut ret = String::new();
+    for _ in 0..size {
+        ret.push_str(&format!(
+            "p={},{} v={},{}\n",
+            rng.between(0, 100),
+            rng.between(0, 102),
+            rng.between(-100, 100),
+            rng.between(-100, 100)
+        ));
+    }
+    ret
+}
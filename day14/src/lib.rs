@@ -0,0 +1,387 @@
+pub mod generate;
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use anyhow::Result;
+use common::{
+    input::Named,
+    parse::{self, Cursor},
+    Cancelled, ParseError, Progress, Solution,
+};
+use grid::{Animation, Grid, Point};
+
+/// (height, width) of the bathroom, the input itself does not tell which one it is: only
+/// the named sample is in the small room
+const REAL_SIZE: Point = Point::new(103, 101);
+const SAMPLE_SIZE: Point = Point::new(7, 11);
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: Point,
+    velocity: Point,
+}
+
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    robots: Vec<Robot>,
+    /// how many robots stand on each tile
+    map: Grid<i32>,
+}
+
+impl Robot {
+    /// `p=x,y v=x,y`, the input is written as x,y which is col,row
+    fn parse_line(mut line: Cursor) -> Result<Self, ParseError> {
+        line.literal("p=")?;
+        let p_col = line.unsigned()?;
+        line.literal(",")?;
+        let p_row = line.unsigned()?;
+        line.literal(" v=")?;
+        let v_col = line.signed()?;
+        line.literal(",")?;
+        let v_row = line.signed()?;
+        line.end()?;
+        Ok(Self {
+            position: Point::new(p_row, p_col),
+            velocity: Point::new(v_row, v_col),
+        })
+    }
+
+    /// the robots teleport to the other side at the edges, so the room wraps around
+    fn action(&self, size: Point, duration: u32) -> Point {
+        (self.position + self.velocity * duration as isize).rem_euclid(size)
+    }
+
+    fn action_per_scecond(&mut self, size: Point) {
+        self.position = (self.position + self.velocity).rem_euclid(size);
+    }
+}
+
+impl Puzzle {
+    pub fn load_data(filename: PathBuf, height: i32, width: i32) -> Result<Self> {
+        let robots = Self::parse_robots(&fs::read_to_string(&filename)?)
+            .map_err(|e| e.with_file(filename))?;
+        Ok(Self::with_size(robots, height, width))
+    }
+
+    fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+        parse::lines(input).map(Robot::parse_line).collect()
+    }
+
+    /// the robots of `input` in a room of `size`, each of them has to stand in it
+    fn parse_in(input: &str, size: Point) -> Result<Self> {
+        let robots = Self::parse_robots(input)?;
+        if robots.is_empty() {
+            return Err(ParseError::end_of_input(input, "a robot").into());
+        }
+        // one robot per line, so the index of a robot tells its line
+        if let Some(idx) = robots
+            .iter()
+            .position(|robot| robot.position.row >= size.row || robot.position.col >= size.col)
+        {
+            let found = format!(
+                "p={},{}",
+                robots[idx].position.col, robots[idx].position.row
+            );
+            let expected = format!("a robot in the {}x{} room", size.col, size.row);
+            return Err(ParseError::new(idx + 1, 1, expected, found).into());
+        }
+        Ok(Self::with_size(robots, size.row as i32, size.col as i32))
+    }
+
+    /// the room wraps around, so a velocity only matters modulo its size; taken that way, no
+    /// velocity times a number of seconds can overflow
+    fn with_size(mut robots: Vec<Robot>, height: i32, width: i32) -> Self {
+        let size = Point::new(height as isize, width as isize);
+        for robot in robots.iter_mut() {
+            robot.velocity = robot.velocity.rem_euclid(size);
+        }
+        Self {
+            robots,
+            map: Grid::filled(height as usize, width as usize, 0),
+        }
+    }
+
+    /// (height, width) of the room
+    fn size(&self) -> Point {
+        Point::from((self.map.height(), self.map.width()))
+    }
+
+    fn robot_move(&mut self, duration: u32) {
+        let size = self.size();
+        for robot in self.robots.iter() {
+            self.map[robot.action(size, duration)] += 1;
+        }
+    }
+
+    /// move the robots second by second until most of them are next to each other (the tree),
+    /// and return how many seconds it takes, one progress tick per second.
+    ///
+    /// After height * width seconds every robot is back where it started, so when there is
+    /// no tree by then there never will be.
+    fn robot_move_per_seoncd(&mut self, progress: &mut Progress) -> Result<Option<u32>, Cancelled> {
+        let size = self.size();
+        let period = (size.row * size.col) as u32;
+        for round in 1..=period {
+            progress.tick(Some(period as u64))?;
+            self.robot_move_one_second(size);
+            if self.is_majority_robots_next_to_each_other() {
+                return Ok(Some(round));
+            }
+            common::trace!("round {} is not the tree", round);
+        }
+        Ok(None)
+    }
+
+    fn robot_move_one_second(&mut self, size: Point) {
+        for robot in self.robots.iter_mut() {
+            self.map[robot.position] -= 1;
+            robot.action_per_scecond(size);
+            self.map[robot.position] += 1;
+        }
+    }
+
+    /// the robots second by second, one frame per second, for `seconds`, or until the tree
+    /// shows up when `seconds` is `None`
+    pub fn animate(
+        &self,
+        seconds: Option<u32>,
+        animation: &mut Animation<impl Write>,
+    ) -> io::Result<()> {
+        let mut puzzle = self.clone();
+        puzzle.init_map();
+        let size = puzzle.size();
+        let last = seconds.unwrap_or((size.row * size.col) as u32);
+        for second in 1..=last {
+            puzzle.robot_move_one_second(size);
+            if seconds.is_none() && puzzle.is_majority_robots_next_to_each_other() {
+                return animation.finish(&puzzle.frame(), &format!("second {}, the tree", second));
+            }
+            if !animation.frame(&puzzle.frame(), &format!("second {}", second))? {
+                return Ok(());
+            }
+        }
+        animation.finish(&puzzle.frame(), &format!("second {}", last))
+    }
+
+    /// the room at the second of the tree, or after a whole period when there is none
+    pub fn picture(&self) -> Grid<char> {
+        let mut puzzle = self.clone();
+        puzzle.init_map();
+        // nothing cancels without a limit
+        let _ = puzzle.robot_move_per_seoncd(&mut Progress::default());
+        puzzle.frame()
+    }
+
+    /// '#' where at least one robot stands
+    fn frame(&self) -> Grid<char> {
+        self.map.map(|count| if *count > 0 { '#' } else { '.' })
+    }
+
+    fn is_majority_robots_next_to_each_other(&self) -> bool {
+        let mut grouped_robots = HashSet::new();
+        let mut neighbours = HashSet::new();
+        for robot in self.robots.iter() {
+            let position = (robot.position.row as usize, robot.position.col as usize);
+            if neighbours.contains(&position) {
+                grouped_robots.insert(position);
+            }
+            // store all possible position next to this robot, diagonals included
+            neighbours.extend(self.map.neighbours8(position));
+        }
+        grouped_robots.len() >= 250
+    }
+
+    fn init_map(&mut self) {
+        // reset the whole map
+        self.map.fill(0);
+        for robot in self.robots.iter() {
+            self.map[robot.position] += 1;
+        }
+    }
+
+    fn cal_safety_factor(&self) -> i32 {
+        // first quarant
+        let mut first = 0;
+        let mut second = 0;
+        let mut third = 0;
+        let mut fourth = 0;
+        let (mid_row, mid_col) = (self.map.height() / 2, self.map.width() / 2);
+        for ((row_idx, col_idx), count) in self.map.iter() {
+            if row_idx < mid_row && col_idx < mid_col {
+                first += count;
+            } else if row_idx < mid_row && col_idx > mid_col {
+                second += count;
+            } else if row_idx > mid_row && col_idx < mid_col {
+                third += count;
+            } else if row_idx > mid_row && col_idx > mid_col {
+                fourth += count;
+            }
+        }
+        common::debug!(
+            "quadrants: {:?}, {:?}, {:?}, {:?}",
+            first,
+            second,
+            third,
+            fourth
+        );
+        first * second * third * fourth
+    }
+}
+
+/// The safety factor of part one, or the second of part two the robots draw the tree at,
+/// which they may never do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    SafetyFactor(i32),
+    Tree(Option<u32>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::SafetyFactor(factor) => write!(f, "{}", factor),
+            Answer::Tree(Some(second)) => write!(f, "{}", second),
+            Answer::Tree(None) => write!(f, "no tree"),
+        }
+    }
+}
+
+impl Solution for Puzzle {
+    type Answer = Answer;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse_in(input, REAL_SIZE)
+    }
+
+    /// the sample of the puzzle text is in a 11x7 room
+    fn parse_named(input: &str, name: Option<Named>) -> Result<Self> {
+        match name {
+            Some(Named::Sample) => Self::parse_in(input, SAMPLE_SIZE),
+            _ => Self::parse(input),
+        }
+    }
+
+    fn part_one(&self) -> Answer {
+        let mut puzzle = self.clone();
+        puzzle.robot_move(100);
+        Answer::SafetyFactor(puzzle.cal_safety_factor())
+    }
+
+    /// no second when the robots never draw the tree, like in the example
+    fn part_two(&self) -> Answer {
+        // nothing cancels without a limit
+        self.part_two_with(&mut Progress::default()).unwrap()
+    }
+
+    fn part_two_with(&self, progress: &mut Progress) -> Result<Answer, Cancelled> {
+        let mut puzzle = self.clone();
+        puzzle.init_map();
+        Ok(Answer::Tree(puzzle.robot_move_per_seoncd(progress)?))
+    }
+}
+
+common::impl_from_str!(Puzzle);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(14, Puzzle);
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{generate, Answer, Puzzle, REAL_SIZE, SAMPLE_SIZE};
+    use common::{manifest_path, Solution};
+
+    #[test]
+    fn test_part_1() {
+        let puzzle = Puzzle::load_data(manifest_path!("data_test.txt"), 7, 11).unwrap();
+        assert_eq!(puzzle.part_one(), Answer::SafetyFactor(12));
+    }
+
+    #[test]
+    fn test_parse() {
+        let error = "p=0,4 v=3,-3\np=6,3 v=-1".parse::<Puzzle>().unwrap_err();
+        assert_eq!(error.to_string(), "2:11: expected \",\", found end of line");
+        let error = "p=0,4 v=3,-3 p=1,1".parse::<Puzzle>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "1:13: expected end of line, found \" p=1,1\""
+        );
+        let error = "p=101,0 v=1,1".parse::<Puzzle>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "1:1: expected a robot in the 101x103 room, found p=101,0"
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let error = "p=18446744073709551615,0 v=1,1"
+            .parse::<Puzzle>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "1:3: expected a number that fits, found \"18446744073709551615\""
+        );
+        // the same robot as one with a velocity of 89,24 in the real room
+        let fast: Puzzle = "p=0,0 v=9223372036854775807,-9223372036854775808"
+            .parse()
+            .unwrap();
+        let slow: Puzzle = "p=0,0 v=89,24".parse().unwrap();
+        assert_eq!(
+            fast.robots[0].action(REAL_SIZE, 100),
+            slow.robots[0].action(REAL_SIZE, 100)
+        );
+        fast.part_two();
+    }
+
+    #[test]
+    fn test_size() {
+        // only the named sample is in the small room, not a few robots that happen to fit in it
+        let sample = Puzzle::load(&manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(sample.size(), SAMPLE_SIZE);
+        assert_eq!(sample.part_one(), Answer::SafetyFactor(12));
+        let text = std::fs::read_to_string(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(text.parse::<Puzzle>().unwrap().size(), REAL_SIZE);
+        let generated: Puzzle = generate::generate(3, 0).parse().unwrap();
+        assert_eq!(generated.size(), REAL_SIZE);
+    }
+
+    #[test]
+    fn test_frame() {
+        // two robots start on the same tile, and one of them stays there
+        let mut puzzle = "p=1,1 v=0,0\np=1,1 v=1,0\np=4,2 v=-1,1"
+            .parse::<Puzzle>()
+            .unwrap();
+        puzzle.init_map();
+        let size = puzzle.size();
+        for _ in 0..3 {
+            puzzle.robot_move_one_second(size);
+            let tiles: HashSet<_> = puzzle
+                .frame()
+                .iter()
+                .filter(|(_, tile)| **tile == '#')
+                .map(|(position, _)| position)
+                .collect();
+            let robots: HashSet<_> = puzzle
+                .robots
+                .iter()
+                .map(|robot| (robot.position.row as usize, robot.position.col as usize))
+                .collect();
+            assert_eq!(tiles, robots);
+        }
+    }
+
+    #[test]
+    fn test_part_2() {
+        // the example has no tree, it only has to give up instead of looping forever
+        let puzzle = Puzzle::load_data(manifest_path!("data_test.txt"), 7, 11).unwrap();
+        assert_eq!(puzzle.part_two(), Answer::Tree(None));
+    }
+}
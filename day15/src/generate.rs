@@ -0,0 +1,33 @@
+use common::Rng;
+use grid::Grid;
+
+/// a `size` x `size` warehouse walled all around, with boxes, a few walls inside and one robot,
+/// followed by `size` * 20 moves
+pub fn generate(size: usize, seed: u64) -> String {
+    let size = size.max(3);
+    let mut rng = Rng::new(seed);
+    let mut map = Grid::filled(size, size, '#');
+    for row in 1..size - 1 {
+        for col in 1..size - 1 {
+            map[(row, col)] = match rng.below(100) {
+                0..=4 => '#',
+                5..=24 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    map[(
+        rng.between(1, size as i64 - 2) as usize,
+        rng.between(1, size as i64 - 2) as usize,
+    )] = '@';
+    let moves: Vec<char> = (0..size * 20)
+        .map(|_| *rng.pick(&['^', '>', 'v', '<']))
+        .collect();
+    let mut ret = map.to_string();
+    for line in moves.chunks(70) {
+        ret.push('\n');
+        ret.extend(line);
+    }
+    ret.push('\n');
+    ret
+}
@@ -0,0 +1,290 @@
+pub mod generate;
+
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use anyhow::Result;
+use common::{ParseError, Solution};
+use grid::{Animation, Direction, Grid, Point};
+
+#[derive(Debug, Clone)]
+pub struct Problem {
+    map: Grid<char>,
+    moves: Vec<Direction>,
+    position: Point,
+}
+
+impl Problem {
+    pub fn load_data(filename: PathBuf) -> Result<Self> {
+        Self::load(&filename)
+    }
+
+    fn moving(&mut self) {
+        let mut position = self.position;
+        for direction in self.moves.iter() {
+            position = Self::move_in_line(&mut self.map, position, *direction);
+        }
+    }
+
+    /// push the robot and the boxes in front of it one step, and return where the robot ends
+    fn move_in_line(map: &mut Grid<char>, position: Point, direction: Direction) -> Point {
+        if Self::can_move(map, position, direction) {
+            map[position] = '.';
+            Self::change_map(map, position + direction, direction);
+            position + direction
+        } else {
+            position
+        }
+    }
+
+    fn can_move(map: &Grid<char>, mut position: Point, direction: Direction) -> bool {
+        while map[position] != '#' {
+            if map[position] == '.' {
+                return true;
+            }
+            position += direction;
+        }
+        false
+    }
+
+    fn change_map(map: &mut Grid<char>, mut position: Point, direction: Direction) {
+        let mut prev_char = '@';
+        while map[position] != '#' {
+            if map[position] == '.' {
+                map[position] = prev_char;
+                break;
+            } else {
+                std::mem::swap(&mut map[position], &mut prev_char);
+                position += direction;
+            }
+        }
+    }
+
+    /// everything except the robot is twice as wide in part two
+    fn widen(&self) -> Self {
+        let cells = self
+            .map
+            .rows()
+            .flatten()
+            .flat_map(|charactor| match charactor {
+                '#' => ['#', '#'],
+                'O' => ['[', ']'],
+                '@' => ['@', '.'],
+                _ => ['.', '.'],
+            })
+            .collect();
+        Self {
+            map: Grid::new(self.map.height(), self.map.width() * 2, cells),
+            moves: self.moves.clone(),
+            position: Point::new(self.position.row, self.position.col * 2),
+        }
+    }
+
+    /// same as `moving`, but on the widened map, where pushing a box up or down
+    /// can push the two boxes sitting on its halves as well
+    fn moving_wide(&mut self) {
+        let mut position = self.position;
+        for direction in self.moves.iter() {
+            position = Self::move_wide(&mut self.map, position, *direction);
+        }
+    }
+
+    fn move_wide(map: &mut Grid<char>, position: Point, direction: Direction) -> Point {
+        if !direction.is_vertical() {
+            Self::move_in_line(map, position, direction)
+        } else if Self::push_vertically(map, position, direction) {
+            position + direction
+        } else {
+            position
+        }
+    }
+
+    /// the robot going through its moves, one frame per move, on the wide map of part two when `wide`
+    pub fn animate(&self, wide: bool, animation: &mut Animation<impl Write>) -> io::Result<()> {
+        let mut problem = if wide { self.widen() } else { self.clone() };
+        let mut position = problem.position;
+        for (idx, direction) in self.moves.iter().enumerate() {
+            position = if wide {
+                Self::move_wide(&mut problem.map, position, *direction)
+            } else {
+                Self::move_in_line(&mut problem.map, position, *direction)
+            };
+            let status = format!("move {} of {}: {}", idx + 1, self.moves.len(), direction);
+            if !animation.frame(&problem.map, &status)? {
+                return Ok(());
+            }
+        }
+        let status = format!("done, the GPS sum is {}", problem.cal_coordinates());
+        animation.finish(&problem.map, &status)
+    }
+
+    /// the wide warehouse of part two once the robot made all its moves
+    pub fn picture(&self) -> Grid<char> {
+        let mut problem = self.widen();
+        problem.moving_wide();
+        problem.map
+    }
+
+    /// collect the robot and every box it pushes row by row, then move them from the farthest row back,
+    /// so each cell lands on a place that is already free
+    fn push_vertically(map: &mut Grid<char>, position: Point, direction: Direction) -> bool {
+        let mut to_move = vec![position];
+        let mut seen = HashSet::new();
+        seen.insert(position);
+        let mut idx = 0;
+        while idx < to_move.len() {
+            let next = to_move[idx] + direction;
+            idx += 1;
+            let pushed = match map[next] {
+                '#' => return false,
+                'O' => vec![next],
+                '[' => vec![next, next + Direction::Right],
+                ']' => vec![next, next + Direction::Left],
+                _ => vec![],
+            };
+            for point in pushed {
+                if seen.insert(point) {
+                    to_move.push(point);
+                }
+            }
+        }
+        for point in to_move.into_iter().rev() {
+            map[point + direction] = map[point];
+            map[point] = '.';
+        }
+        true
+    }
+
+    /// a wide box is measured from its left half
+    fn cal_coordinates(&self) -> u64 {
+        let mut ret = 0;
+        for ((row, col), charactor) in self.map.iter() {
+            if matches!(charactor, 'O' | '[') {
+                ret += (row * 100 + col) as u64;
+            }
+        }
+        ret
+    }
+}
+
+impl Solution for Problem {
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut lines = input.lines().enumerate();
+        // read map in, it ends at the first empty line
+        let map = lines
+            .by_ref()
+            .map(|(_, line)| line)
+            .take_while(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let map = Grid::parse(&map).map_err(ParseError::from)?;
+        // the moves only stop at a wall, so there has to be one all around
+        if let Some(((row, col), charactor)) = map.iter().find(|((row, col), charactor)| {
+            let border =
+                *row == 0 || *col == 0 || *row + 1 == map.height() || *col + 1 == map.width();
+            border && **charactor != '#'
+        }) {
+            return Err(ParseError::new(
+                row + 1,
+                col + 1,
+                "a wall '#' around the map",
+                format!("{:?}", charactor),
+            )
+            .into());
+        }
+        let position = map
+            .position(|charactor| *charactor == '@')
+            .map(Point::from)
+            .ok_or_else(|| ParseError::new(1, 1, "a robot '@' on the map", "no robot"))?;
+        // load moves
+        let mut moves = Vec::new();
+        for (idx, buf) in lines {
+            for (col, charactor) in buf.char_indices() {
+                let direction = Direction::try_from(charactor).map_err(|_| {
+                    ParseError::in_line(
+                        idx + 1,
+                        buf,
+                        &buf[col..col + charactor.len_utf8()],
+                        "a move",
+                    )
+                })?;
+                moves.push(direction);
+            }
+        }
+        Ok(Self {
+            map,
+            moves,
+            position,
+        })
+    }
+
+    fn part_one(&self) -> u64 {
+        let mut problem = self.clone();
+        problem.moving();
+        problem.cal_coordinates()
+    }
+
+    fn part_two(&self) -> u64 {
+        let mut problem = self.widen();
+        problem.moving_wide();
+        problem.cal_coordinates()
+    }
+}
+
+common::impl_from_str!(Problem);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(15, Problem);
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::Problem;
+    use common::{manifest_path, Solution};
+    use grid::{Animation, Playback};
+
+    #[test]
+    fn test_load() {
+        let problem = Problem::load_data(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(problem.part_one(), 10092);
+        assert_eq!(problem.part_two(), 9021);
+    }
+
+    #[test]
+    fn test_part_two() {
+        // pushing up a box that sits on the halves of two boxes pushes both of them
+        let problem = Problem::parse(
+            "#######\n#...#.#\n#.....#\n#..OO@#\n#..O..#\n#.....#\n#######\n\n<vv<<^^<<^^\n",
+        )
+        .unwrap();
+        assert_eq!(problem.part_two(), 105 + 207 + 306);
+    }
+
+    #[test]
+    fn test_parse() {
+        let error = Problem::parse("@.O\n\n>>\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "1:1: expected a wall '#' around the map, found '@'"
+        );
+    }
+
+    #[test]
+    fn test_animate() {
+        let problem = Problem::load_data(manifest_path!("data_test.txt")).unwrap();
+        let playback = Playback {
+            delay: Duration::ZERO,
+            skip: 100,
+            paused: false,
+        };
+        let mut animation = Animation::new(Vec::new(), playback);
+        problem.animate(true, &mut animation).unwrap();
+        assert_eq!(animation.frames(), problem.moves.len());
+    }
+}
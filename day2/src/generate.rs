@@ -0,0 +1,24 @@
+use common::Rng;
+
+/// `size` reports of 5 to 8 levels, mostly going one way so some of them are safe
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut ret = String::new();
+    for _ in 0..size {
+        let direction = if rng.chance(50) { 1 } else { -1 };
+        let mut level = rng.between(1, 99);
+        let mut levels = vec![level.to_string()];
+        for _ in 1..rng.between(5, 8) {
+            let step = if rng.chance(85) {
+                direction * rng.between(1, 3)
+            } else {
+                rng.between(-4, 4)
+            };
+            level += step;
+            levels.push(level.to_string());
+        }
+        ret.push_str(&levels.join(" "));
+        ret.push('\n');
+    }
+    ret
+}
@@ -1,40 +1,20 @@
-use anyhow::{anyhow, Result};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+pub mod generate;
+
+use anyhow::Result;
+use common::{parse_field, CrossCheck, Disagreement, ParseError, Solution};
 use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
-struct DataSet {
+pub struct DataSet {
     data_frame: Vec<Vec<i32>>,
-    num_of_safe: u32,
 }
 
 impl DataSet {
-    fn init(file_path: PathBuf) -> Result<Self> {
-        let file = File::open(file_path)?;
-        let reader = BufReader::new(file);
-        let mut data_frame = Vec::new();
-        let mut num_of_safe = 0;
-
-        for line in reader.lines() {
-            let line_str = line?;
-            let nums: Vec<i32> = line_str
-                .split_whitespace()
-                .map(|num| num.parse::<i32>().unwrap())
-                .collect();
-            if DataSet::is_safe(&nums) {
-                num_of_safe += 1;
-            }
-            data_frame.push(nums);
-        }
-
-        Ok(Self {
-            data_frame,
-            num_of_safe,
-        })
+    pub fn init(file_path: PathBuf) -> Result<Self> {
+        Self::load(&file_path)
     }
 
-    fn is_safe(nums: &Vec<i32>) -> bool {
+    fn is_safe(nums: &[i32]) -> bool {
         if nums.len() < 2 {
             return true;
         }
@@ -53,41 +33,154 @@ impl DataSet {
             }
             idx += 1;
         }
-        return true;
+        true
+    }
+
+    fn count_safe(&self) -> u32 {
+        self.data_frame
+            .iter()
+            .filter(|line| DataSet::is_safe(line))
+            .count() as u32
     }
 
     fn tolerate_a_single_bad_level(&self) -> u32 {
-        let mut ret = 0;
-        for line in &self.data_frame {
-            if DataSet::is_safe(line) {
-                ret += 1;
-            } else {
-                for idx in 0..line.len() {
-                    let mut clone_line = line.clone();
-                    clone_line.remove(idx);
-                    if DataSet::is_safe(&clone_line) {
-                        ret += 1;
-                        break;
-                    }
-                }
+        self.data_frame
+            .iter()
+            .filter(|line| DataSet::is_safe_removing_any_level(line))
+            .count() as u32
+    }
+
+    /// remove each level in turn, the reference `is_safe_without_a_level` is checked against
+    fn is_safe_removing_any_level(nums: &[i32]) -> bool {
+        if DataSet::is_safe(nums) {
+            return true;
+        }
+        (0..nums.len()).any(|idx| {
+            let mut clone_line = nums.to_vec();
+            clone_line.remove(idx);
+            DataSet::is_safe(&clone_line)
+        })
+    }
+
+    /// the index of the first pair of levels not going by 1 to 3 the way `increasing` says
+    fn first_bad_pair(nums: &[i32], increasing: bool) -> Option<usize> {
+        nums.windows(2).position(|pair| {
+            !(1..=3).contains(&pair[0].abs_diff(pair[1])) || (pair[1] > pair[0]) != increasing
+        })
+    }
+
+    /// whether the report is safe with at most one level removed, in linear time.
+    ///
+    /// Going one way, the levels of the first bad pair stay next to each other whatever other
+    /// level is removed, so removing one of those two is the only chance; trying both ways
+    /// covers every report.
+    fn is_safe_without_a_level(nums: &[i32]) -> bool {
+        [true, false].into_iter().any(|increasing| {
+            let Some(bad) = DataSet::first_bad_pair(nums, increasing) else {
+                return true;
+            };
+            [bad, bad + 1].into_iter().any(|idx| {
+                let mut clone_line = nums.to_vec();
+                clone_line.remove(idx);
+                DataSet::first_bad_pair(&clone_line, increasing).is_none()
+            })
+        })
+    }
+}
+
+impl Solution for DataSet {
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut data_frame = Vec::new();
+        for (idx, line_str) in input.lines().enumerate() {
+            let nums = line_str
+                .split_whitespace()
+                .map(|num| parse_field(idx + 1, line_str, num, "a level"))
+                .collect::<Result<Vec<i32>, _>>()?;
+            if nums.is_empty() {
+                return Err(ParseError::end_of_line(idx + 1, line_str, "a level").into());
             }
+            data_frame.push(nums);
         }
-        ret
+        if data_frame.is_empty() {
+            return Err(ParseError::end_of_input(input, "a report").into());
+        }
+        Ok(Self { data_frame })
+    }
+
+    fn part_one(&self) -> u32 {
+        self.count_safe()
+    }
+
+    fn part_two(&self) -> u32 {
+        self.tolerate_a_single_bad_level()
+    }
+}
+
+impl CrossCheck for DataSet {
+    fn cross_check(&self) -> Vec<Disagreement> {
+        self.data_frame
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, line)| {
+                Disagreement::compare(
+                    "is_safe_without_a_level against removing each level",
+                    format_args!("line {}", idx + 1),
+                    DataSet::is_safe_removing_any_level(line),
+                    DataSet::is_safe_without_a_level(line),
+                )
+            })
+            .collect()
     }
 }
 
+common::impl_from_str!(DataSet);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(2, DataSet);
+
 #[cfg(test)]
 mod tests {
-
     use super::*;
+    use common::manifest_path;
 
     #[test]
-    fn it_works() {
-        let mut file_path = std::env::current_dir().unwrap();
-        file_path.push("data.txt");
-        let data_set = DataSet::init(file_path).unwrap();
-        println!("{:?}", data_set);
+    fn test_sample() {
+        let data_set = DataSet::init(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(data_set.part_one(), 2);
+        assert_eq!(data_set.part_two(), 4);
+    }
+
+    #[test]
+    fn test_cross_check() {
+        for seed in 0..20 {
+            let data_set: DataSet = generate::generate(50, seed).parse().unwrap();
+            assert_eq!(data_set.cross_check(), vec![]);
+        }
+        // the first level, or the second, sets the wrong direction
+        let data_set: DataSet = "5 1 2 3\n1 5 4 3 2\n1 2 2 9\n3 2 4 5\n7".parse().unwrap();
+        assert_eq!(data_set.cross_check(), vec![]);
+        // every report of up to 5 levels out of 1 to 5
+        for len in 1..=5 {
+            for code in 0..5_u32.pow(len) {
+                let line: Vec<i32> = (0..len)
+                    .map(|idx| (code / 5_u32.pow(idx) % 5) as i32 + 1)
+                    .collect();
+                assert_eq!(
+                    DataSet::is_safe_without_a_level(&line),
+                    DataSet::is_safe_removing_any_level(&line),
+                    "{:?}",
+                    line
+                );
+            }
+        }
+    }
 
-        println!("{:?}", data_set.tolerate_a_single_bad_level());
+    #[test]
+    fn it_works() {
+        let data_set = DataSet::init(manifest_path!("data.txt")).unwrap();
+        assert_eq!(data_set.part_one(), 269);
+        assert_eq!(data_set.part_two(), 337);
     }
 }
@@ -0,0 +1,26 @@
+use common::Rng;
+
+const JUNK: &[u8] = b"%&*!@^$()[]{}<>,?:;' mulxdont";
+
+/// `size` instructions, valid `mul(a,b)`, `do()`, `don't()` or broken ones, with junk in between
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut ret = String::new();
+    for _ in 0..size {
+        for _ in 0..rng.below(10) {
+            ret.push(*rng.pick(JUNK) as char);
+        }
+        let left = rng.between(1, 999);
+        let right = rng.between(1, 999);
+        let instruction = match rng.below(10) {
+            0..=5 => format!("mul({},{})", left, right),
+            6 => "do()".to_string(),
+            7 => "don't()".to_string(),
+            8 => format!("mul({},{}]", left, right),
+            _ => format!("mul[{} {})", left, right),
+        };
+        ret.push_str(&instruction);
+    }
+    ret.push('\n');
+    ret
+}
@@ -1,37 +1,43 @@
-use std::fs::File;
-use std::io::Read;
+pub mod generate;
+
 use std::path::PathBuf;
-struct Solution;
+
+use anyhow::Result;
+use common::{ParseError, Solution as _};
+
+/// the corrupted memory, kept as raw bytes since the scanner works byte by byte
+pub struct Solution {
+    memory: Vec<u8>,
+}
 
 impl Solution {
-    fn get_answer(filename: PathBuf) -> u64 {
-        let mut file = File::open(filename).expect("File does not exists");
-        let mut buffer = Vec::new();
-        let _ = file.read_to_end(&mut buffer);
+    pub fn load_data(filename: PathBuf) -> Result<Self> {
+        Self::load(&filename)
+    }
+
+    fn get_answer(&self) -> u64 {
+        let buffer = &self.memory;
         let mut index = 0;
         let mut ret = 0_u64;
         while index < buffer.len() {
-            if buffer[index].is_ascii() && buffer[index] == 'm' as u8 && index + 4 < buffer.len() {
+            if buffer[index].is_ascii() && buffer[index] == b'm' && index + 4 < buffer.len() {
                 // check "mul("
-                if Self::check_mul(&buffer, index) {
+                if Self::check_mul(buffer, index) {
                     index += 4;
                     // get first number
-                    let num1 = Self::get_number(&buffer, &mut index);
+                    let num1 = Self::get_number(buffer, &mut index);
                     // check if format as first_number,
-                    if index < buffer.len()
-                        && buffer[index].is_ascii()
-                        && (buffer[index] == ',' as u8)
-                    {
+                    if index < buffer.len() && buffer[index].is_ascii() && (buffer[index] == b',') {
                         // skip the ,
                         index += 1;
                         // get second number
-                        let num2 = Self::get_number(&buffer, &mut index);
+                        let num2 = Self::get_number(buffer, &mut index);
                         // check if format as second_number)
-                        if index < buffer.len()
-                            && buffer[index].is_ascii()
-                            && buffer[index] == ')' as u8
+                        if index < buffer.len() && buffer[index].is_ascii() && buffer[index] == b')'
                         {
-                            ret += num1 * num2;
+                            if let (Some(num1), Some(num2)) = (num1, num2) {
+                                ret += num1 * num2;
+                            }
                         }
                         // skip the ) or just step forward
                         index += 1;
@@ -51,19 +57,17 @@ impl Solution {
         ret
     }
 
-    fn get_answer_with_ops(filename: PathBuf) -> u64 {
-        let mut file = File::open(filename).expect("File does not exists");
-        let mut buffer = Vec::new();
-        let _ = file.read_to_end(&mut buffer);
+    fn get_answer_with_ops(&self) -> u64 {
+        let buffer = &self.memory;
         let mut index = 0;
         let mut ret = 0_u64;
         let mut is_do = true;
         while index < buffer.len() {
-            if buffer[index].is_ascii() && buffer[index] == 'd' as u8 {
-                if index + 7 < buffer.len() && Self::check_dont(&mut buffer, index) {
+            if buffer[index].is_ascii() && buffer[index] == b'd' {
+                if index + 7 < buffer.len() && Self::check_dont(buffer, index) {
                     index += 7;
                     is_do = false;
-                } else if index + 4 < buffer.len() && Self::check_do(&mut buffer, index) {
+                } else if index + 4 < buffer.len() && Self::check_do(buffer, index) {
                     index += 4;
                     is_do = true;
                 }
@@ -72,30 +76,27 @@ impl Solution {
                 break;
             }
             if buffer[index].is_ascii()
-                && buffer[index] == 'm' as u8
+                && buffer[index] == b'm'
                 && index + 4 < buffer.len()
                 && is_do
             {
                 // check "mul("
-                if Self::check_mul(&buffer, index) {
+                if Self::check_mul(buffer, index) {
                     index += 4;
                     // get first number
-                    let num1 = Self::get_number(&buffer, &mut index);
+                    let num1 = Self::get_number(buffer, &mut index);
                     // check if format as first_number,
-                    if index < buffer.len()
-                        && buffer[index].is_ascii()
-                        && (buffer[index] == ',' as u8)
-                    {
+                    if index < buffer.len() && buffer[index].is_ascii() && (buffer[index] == b',') {
                         // skip the ,
                         index += 1;
                         // get second number
-                        let num2 = Self::get_number(&buffer, &mut index);
+                        let num2 = Self::get_number(buffer, &mut index);
                         // check if format as second_number)
-                        if index < buffer.len()
-                            && buffer[index].is_ascii()
-                            && buffer[index] == ')' as u8
+                        if index < buffer.len() && buffer[index].is_ascii() && buffer[index] == b')'
                         {
-                            ret += num1 * num2;
+                            if let (Some(num1), Some(num2)) = (num1, num2) {
+                                ret += num1 * num2;
+                            }
                         }
                         // skip the ) or just step forward
                         index += 1;
@@ -129,26 +130,76 @@ impl Solution {
         file.get(idx..idx + 4) == Some(b"do()")
     }
 
-    fn get_number(file: &[u8], idx: &mut usize) -> u64 {
-        let mut ret_num = 0;
+    /// skip the digits at `idx`, they are a number only when there are 1 to 3 of them
+    fn get_number(file: &[u8], idx: &mut usize) -> Option<u64> {
+        let start = *idx;
         while *idx < file.len() && file[*idx].is_ascii_digit() {
-            ret_num = ret_num * 10 + (file[*idx] - b'0') as u64;
             *idx += 1;
         }
-        ret_num
+        if !(1..=3).contains(&(*idx - start)) {
+            return None;
+        }
+        let mut ret_num = 0;
+        for digit in &file[start..*idx] {
+            ret_num = ret_num * 10 + (digit - b'0') as u64;
+        }
+        Some(ret_num)
     }
 }
 
+impl common::Solution for Solution {
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        if input.trim().is_empty() {
+            return Err(ParseError::end_of_input(input, "the corrupted memory").into());
+        }
+        Ok(Self {
+            memory: input.as_bytes().to_vec(),
+        })
+    }
+
+    fn part_one(&self) -> u64 {
+        self.get_answer()
+    }
+
+    fn part_two(&self) -> u64 {
+        self.get_answer_with_ops()
+    }
+}
+
+common::impl_from_str!(Solution);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(3, Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::manifest_path;
+
+    #[test]
+    fn test_sample() {
+        let solution = Solution::load_data(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(solution.part_one(), 161);
+        // part two has an example of its own, with do() and don't()
+        let solution = Solution::load_data(manifest_path!("data_test2.txt")).unwrap();
+        assert_eq!(solution.part_two(), 48);
+    }
+
+    #[test]
+    fn test_digits() {
+        let solution: Solution = "mul(999999999999,999999999999)mul(1234,5)mul(,5)mul(999,2)"
+            .parse()
+            .unwrap();
+        assert_eq!(solution.part_one(), 1998);
+        assert_eq!(solution.part_two(), 1998);
+    }
 
     #[test]
     fn it_works() {
-        // Answer 174336360, 88802350
-        let mut filename = std::env::current_dir().unwrap();
-        filename.push("data.txt");
-        println!("{:?}", Solution::get_answer(filename.clone()));
-        println!("{:?}", Solution::get_answer_with_ops(filename));
+        let solution = Solution::load_data(manifest_path!("data.txt")).unwrap();
+        assert_eq!(solution.part_one(), 174336360);
+        assert_eq!(solution.part_two(), 88802350);
     }
 }
@@ -0,0 +1,14 @@
+use common::Rng;
+
+/// a `size` x `size` grid of the letters of XMAS
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut ret = String::new();
+    for _ in 0..size.max(1) {
+        for _ in 0..size.max(1) {
+            ret.push(*rng.pick(&['X', 'M', 'A', 'S']));
+        }
+        ret.push('\n');
+    }
+    ret
+}
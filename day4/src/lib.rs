@@ -1,83 +1,64 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{BufRead, BufReader},
-    ops::Deref,
-    path::PathBuf,
-};
-struct DataSet {
-    data: Vec<Vec<char>>,
-
-    lines: usize,
-    columns: usize,
+pub mod generate;
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use common::{ParseError, Solution};
+use grid::Grid;
+
+pub struct DataSet {
+    data: Grid<char>,
 }
 const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
-const SAMX: [char; 4] = ['S', 'A', 'M', 'X'];
 impl DataSet {
-    fn load_dataset(filename: PathBuf) -> Self {
-        let file = File::open(filename).unwrap();
-        let reader = BufReader::new(file);
-        let mut data: Vec<Vec<char>> = vec![];
-        let mut lines = 0;
-        for line in reader.lines() {
-            let line = line.unwrap();
-            data.push(line.chars().collect());
-            lines += 1;
-        }
-
-        DataSet {
-            columns: data[0].len(),
-            data,
-            lines,
-        }
+    pub fn load_dataset(filename: PathBuf) -> Result<Self> {
+        Self::load(&filename)
     }
 
-    fn bruteforce_count_XMAS(&self) -> u32 {
+    fn bruteforce_count_xmas(&self) -> u32 {
         let mut ret = 0;
         // for each start position
-        for idx_i in 0..self.lines {
-            for idx_j in 0..self.columns {
-                if self.data[idx_i][idx_j] != 'X' {
-                    continue;
-                }
-                // do up check
-                if self.up_check(idx_i, idx_j) {
-                    ret += 1;
-                }
-                // do down check
-                if self.down_check(idx_i, idx_j) {
-                    ret += 1;
-                }
-                // do left check
-                if self.left_check(idx_i, idx_j) {
-                    ret += 1;
-                }
-                // do right check
-                if self.right_check(idx_i, idx_j) {
-                    ret += 1;
-                }
-                // do diagonal check
-                ret += self.diagonal_check(idx_i, idx_j)
+        for (position, c) in self.data.iter() {
+            if *c != 'X' {
+                continue;
             }
+            // do up check
+            if self.up_check(position) {
+                ret += 1;
+            }
+            // do down check
+            if self.down_check(position) {
+                ret += 1;
+            }
+            // do left check
+            if self.left_check(position) {
+                ret += 1;
+            }
+            // do right check
+            if self.right_check(position) {
+                ret += 1;
+            }
+            // do diagonal check
+            ret += self.diagonal_check(position)
         }
         ret
     }
 
-    fn bruteforce_count_cross_MAS(&self) -> u32 {
+    fn bruteforce_count_cross_mas(&self) -> u32 {
         let mut ret = 0;
-        for idx_i in 1..self.lines - 1 {
-            for idx_j in 1..self.columns - 1 {
-                if self.data[idx_i][idx_j] != 'A' {
+        for idx_i in 1..self.data.height().saturating_sub(1) {
+            for idx_j in 1..self.data.width().saturating_sub(1) {
+                if self.data[(idx_i, idx_j)] != 'A' {
                     continue;
                 }
                 // check M on top left and right
                 // check M on right
                 // check M on bottom left and right
                 // check M on left
-                if self.check_M_on_top_left_right(idx_i, idx_j)
-                    || self.check_M_on_right(idx_i, idx_j)
-                    || self.check_M_on_bottom_left_right(idx_i, idx_j)
-                    || self.check_M_on_left(idx_i, idx_j)
+                if self.check_m_on_top_left_right(idx_i, idx_j)
+                    || self.check_m_on_right(idx_i, idx_j)
+                    || self.check_m_on_bottom_left_right(idx_i, idx_j)
+                    || self.check_m_on_left(idx_i, idx_j)
                 {
                     ret += 1;
                 }
@@ -86,143 +67,104 @@ impl DataSet {
         ret
     }
 
-    fn check_M_on_top_left_right(&self, r: usize, c: usize) -> bool {
-        self.data[r - 1][c - 1] == 'M'
-            && self.data[r - 1][c + 1] == 'M'
-            && self.data[r + 1][c - 1] == 'S'
-            && self.data[r + 1][c + 1] == 'S'
+    fn check_m_on_top_left_right(&self, r: usize, c: usize) -> bool {
+        self.data[(r - 1, c - 1)] == 'M'
+            && self.data[(r - 1, c + 1)] == 'M'
+            && self.data[(r + 1, c - 1)] == 'S'
+            && self.data[(r + 1, c + 1)] == 'S'
     }
-    fn check_M_on_right(&self, r: usize, c: usize) -> bool {
-        self.data[r - 1][c + 1] == 'M'
-            && self.data[r + 1][c + 1] == 'M'
-            && self.data[r - 1][c - 1] == 'S'
-            && self.data[r + 1][c - 1] == 'S'
+    fn check_m_on_right(&self, r: usize, c: usize) -> bool {
+        self.data[(r - 1, c + 1)] == 'M'
+            && self.data[(r + 1, c + 1)] == 'M'
+            && self.data[(r - 1, c - 1)] == 'S'
+            && self.data[(r + 1, c - 1)] == 'S'
     }
 
-    fn check_M_on_bottom_left_right(&self, r: usize, c: usize) -> bool {
-        self.data[r + 1][c - 1] == 'M'
-            && self.data[r + 1][c + 1] == 'M'
-            && self.data[r - 1][c - 1] == 'S'
-            && self.data[r - 1][c + 1] == 'S'
+    fn check_m_on_bottom_left_right(&self, r: usize, c: usize) -> bool {
+        self.data[(r + 1, c - 1)] == 'M'
+            && self.data[(r + 1, c + 1)] == 'M'
+            && self.data[(r - 1, c - 1)] == 'S'
+            && self.data[(r - 1, c + 1)] == 'S'
     }
 
-    fn check_M_on_left(&self, r: usize, c: usize) -> bool {
-        self.data[r - 1][c - 1] == 'M'
-            && self.data[r + 1][c - 1] == 'M'
-            && self.data[r - 1][c + 1] == 'S'
-            && self.data[r + 1][c + 1] == 'S'
+    fn check_m_on_left(&self, r: usize, c: usize) -> bool {
+        self.data[(r - 1, c - 1)] == 'M'
+            && self.data[(r + 1, c - 1)] == 'M'
+            && self.data[(r - 1, c + 1)] == 'S'
+            && self.data[(r + 1, c + 1)] == 'S'
     }
 
-    fn up_check(&self, r: usize, c: usize) -> bool {
-        if r >= 3 {
-            let slice: Vec<char> = self.data[r - 3..=r]
-                .iter()
-                .filter_map(|str| str.get(c))
-                .cloned()
-                .collect();
-            slice == SAMX
-        } else {
-            false
-        }
+    /// read 4 letters from the position towards the step, a ray cut by the edge is shorter than XMAS
+    fn is_xmas(&self, position: (usize, usize), step: (isize, isize)) -> bool {
+        self.data.ray(position, step).take(4).eq(XMAS.iter())
     }
 
-    fn down_check(&self, r: usize, c: usize) -> bool {
-        if r < self.lines - 3 {
-            let slice: Vec<char> = self.data[r..=r + 3]
-                .iter()
-                .filter_map(|str| str.get(c))
-                .cloned()
-                .collect();
-            slice == XMAS
-        } else {
-            false
-        }
+    fn up_check(&self, position: (usize, usize)) -> bool {
+        self.is_xmas(position, (-1, 0))
     }
 
-    fn left_check(&self, r: usize, c: usize) -> bool {
-        if c >= 3 {
-            let slice = self
-                .data
-                .get(r)
-                .map(|line| line.get(c - 3..=c).map(|slice| slice.to_vec()))
-                .flatten()
-                .unwrap();
-            slice == SAMX
-        } else {
-            false
-        }
+    fn down_check(&self, position: (usize, usize)) -> bool {
+        self.is_xmas(position, (1, 0))
     }
 
-    fn right_check(&self, r: usize, c: usize) -> bool {
-        if c < self.columns - 3 {
-            let slice = self
-                .data
-                .get(r)
-                .map(|line| line.get(c..=c + 3).map(|slice| slice.to_vec()))
-                .flatten()
-                .unwrap();
-            slice == XMAS
-        } else {
-            false
-        }
+    fn left_check(&self, position: (usize, usize)) -> bool {
+        self.is_xmas(position, (0, -1))
     }
 
-    fn diagonal_check(&self, r: usize, c: usize) -> u32 {
-        let mut ret = 0;
-        // left up
-        if c >= 3 && r >= 3 {
-            let mut slice: Vec<char> = vec![];
-            for step in 0..=3 {
-                slice.push(self.data[r - step][c - step]);
-            }
-            if slice == XMAS {
-                ret += 1;
-            }
-        }
-        // left down
-        if c >= 3 && r < self.lines - 3 {
-            let mut slice: Vec<char> = vec![];
-            for step in 0..=3 {
-                slice.push(self.data[r + step][c - step]);
-            }
-            if slice == XMAS {
-                ret += 1;
-            }
-        }
-        // right up
-        if c < self.columns - 3 && r >= 3 {
-            let mut slice: Vec<char> = vec![];
-            for step in 0..=3 {
-                slice.push(self.data[r - step][c + step]);
-            }
-            if slice == XMAS {
-                ret += 1;
-            }
-        }
-        // right down
-        if c < self.columns - 3 && r < self.lines - 3 {
-            let mut slice: Vec<char> = vec![];
-            for step in 0..=3 {
-                slice.push(self.data[r + step][c + step]);
-            }
-            if slice == XMAS {
-                ret += 1;
-            }
-        }
-        ret
+    fn right_check(&self, position: (usize, usize)) -> bool {
+        self.is_xmas(position, (0, 1))
+    }
+
+    fn diagonal_check(&self, position: (usize, usize)) -> u32 {
+        // left up, left down, right up, right down
+        [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+            .into_iter()
+            .filter(|step| self.is_xmas(position, *step))
+            .count() as u32
     }
 }
 
+impl Solution for DataSet {
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DataSet {
+            // only letters, a line of spaces is not a word search
+            data: Grid::parse_with(input, |c| c.is_ascii_alphabetic().then_some(c))
+                .map_err(ParseError::from)?,
+        })
+    }
+
+    fn part_one(&self) -> u32 {
+        self.bruteforce_count_xmas()
+    }
+
+    fn part_two(&self) -> u32 {
+        self.bruteforce_count_cross_mas()
+    }
+}
+
+common::impl_from_str!(DataSet);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(4, DataSet);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::manifest_path;
+
+    #[test]
+    fn test_sample() {
+        let dataset = DataSet::load_dataset(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(dataset.part_one(), 18);
+        assert_eq!(dataset.part_two(), 9);
+    }
 
     #[test]
     fn it_works() {
-        let mut filename = std::env::current_dir().unwrap();
-        filename.push("data.txt");
-        let dataset = DataSet::load_dataset(filename);
-        println!("{:?}", dataset.bruteforce_count_XMAS()); // should be 2434
-        println!("{:?}", dataset.bruteforce_count_cross_MAS()); // should be 1835
+        let dataset = DataSet::load_dataset(manifest_path!("data.txt")).unwrap();
+        assert_eq!(dataset.part_one(), 2434);
+        assert_eq!(dataset.part_two(), 1835);
     }
 }
@@ -0,0 +1,31 @@
+use common::Rng;
+
+/// `size` updates over up to 90 pages; the rules put every pair of pages in one random order,
+/// and the updates list their pages in any order
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut pages: Vec<i64> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(size.clamp(3, 90));
+    let mut ret = String::new();
+    for (idx, before) in pages.iter().enumerate() {
+        for after in &pages[idx + 1..] {
+            ret.push_str(&format!("{}|{}\n", before, after));
+        }
+    }
+    ret.push('\n');
+    for _ in 0..size {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        // an odd length, so there is a middle page
+        let len = rng.below(pages.len().min(23).div_ceil(2)) * 2 + 1;
+        update.truncate(len);
+        if rng.chance(50) {
+            update.sort_by_key(|page| pages.iter().position(|p| p == page));
+        }
+        let update: Vec<String> = update.iter().map(|page| page.to_string()).collect();
+        ret.push_str(&update.join(","));
+        ret.push('\n');
+    }
+    ret
+}
@@ -1,120 +1,129 @@
+pub mod generate;
+
 use std::{
     collections::{hash_map::Entry, HashMap},
-    fs::File,
-    io::{BufRead, BufReader},
     path::PathBuf,
 };
 
+use anyhow::Result;
+use common::{parse_field, ParseError, Solution};
+
 #[derive(Debug, Clone)]
-struct Dataset {
+pub struct Dataset {
     dict: HashMap<u32, Vec<u32>>,
     updates: Vec<Vec<u32>>,
-    correct_updates: Vec<Vec<u32>>,
-    incorrect_updates: Vec<Vec<u32>>,
-    correct_mid_sum_up: u32,
-    incorrect_mid_sum_up: u32,
-}
-
-enum Type {
-    Correct,
-    Incorrect,
 }
 
 impl Dataset {
-    fn load_dataset(filename: PathBuf) -> Self {
-        let file = File::open(filename).unwrap();
-        let reader = BufReader::new(file);
-        let mut dict: HashMap<u32, Vec<u32>> = HashMap::new();
-        let mut updates: Vec<Vec<u32>> = Vec::new();
-
-        for line in reader.lines() {
-            let line = line.unwrap();
-            if line.contains('|') {
-                let nums = line
-                    .split('|')
-                    .into_iter()
-                    .map(|num| num.parse::<u32>().unwrap())
-                    .collect::<Vec<u32>>();
-                match dict.entry(nums[0]) {
-                    Entry::Occupied(mut entry) => {
-                        entry.get_mut().push(nums[1]);
-                    }
-                    Entry::Vacant(_) => {
-                        let v = vec![nums[1]];
-                        dict.insert(nums[0], v);
-                    }
-                };
-            } else if line.contains(',') {
-                let nums = line
-                    .split(',')
-                    .into_iter()
-                    .map(|num| num.parse::<u32>().unwrap())
-                    .collect::<Vec<u32>>();
-                updates.push(nums);
-            } else {
-                continue;
-            }
-        }
-
-        Self {
-            dict,
-            updates,
-            correct_updates: Vec::new(),
-            incorrect_updates: Vec::new(),
-            correct_mid_sum_up: 0,
-            incorrect_mid_sum_up: 0,
-        }
+    pub fn load_dataset(filename: PathBuf) -> Result<Self> {
+        Self::load(&filename)
     }
 
-    fn filter_correct_updates(&mut self) {
-        for update in &mut self.updates {
+    /// split the updates into (correct, incorrect), the incorrect ones come back re-ordered
+    fn filter_correct_updates(&self) -> (Vec<Vec<u32>>, Vec<Vec<u32>>) {
+        let mut correct_updates = Vec::new();
+        let mut incorrect_updates = Vec::new();
+        for update in &self.updates {
+            let mut update = update.clone();
             let mut correct = true;
             for i in 0..update.len() - 1 {
                 for j in i + 1..update.len() {
-                    if !self.dict.get(&update[i]).unwrap().contains(&update[j]) {
+                    // a page without any rule can not come before another one
+                    if self
+                        .dict
+                        .get(&update[i])
+                        .is_none_or(|after| !after.contains(&update[j]))
+                    {
                         correct = false;
-                        let temp = update[i];
-                        update[i] = update[j];
-                        update[j] = temp;
+                        update.swap(i, j);
                     }
                 }
             }
             if correct {
-                self.correct_updates.push(update.clone());
+                correct_updates.push(update);
             } else {
-                self.incorrect_updates.push(update.clone());
+                incorrect_updates.push(update);
             }
         }
+        (correct_updates, incorrect_updates)
     }
 
-    fn cal_mid_sum_up(&mut self, cal_type: Type) {
-        let updates = match cal_type {
-            Type::Correct => &self.correct_updates,
-            Type::Incorrect => &self.incorrect_updates,
-        };
+    fn cal_mid_sum_up(updates: &[Vec<u32>]) -> u32 {
+        let mut ret = 0;
         for update in updates {
             let mid_idx = update.len() / 2;
-            match cal_type {
-                Type::Correct => self.correct_mid_sum_up += update[mid_idx],
-                Type::Incorrect => self.incorrect_mid_sum_up += update[mid_idx],
+            ret += update[mid_idx];
+        }
+        ret
+    }
+}
+
+impl Solution for Dataset {
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut dict: HashMap<u32, Vec<u32>> = HashMap::new();
+        let mut updates: Vec<Vec<u32>> = Vec::new();
+
+        for (idx, line) in input.lines().enumerate() {
+            if let Some((before, after)) = line.split_once('|') {
+                let before = parse_field(idx + 1, line, before, "a page number")?;
+                let after = parse_field(idx + 1, line, after, "a page number")?;
+                match dict.entry(before) {
+                    Entry::Occupied(mut entry) => {
+                        entry.get_mut().push(after);
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(vec![after]);
+                    }
+                };
+            } else if !line.is_empty() {
+                let nums = line
+                    .split(',')
+                    .map(|num| parse_field(idx + 1, line, num, "a page number"))
+                    .collect::<Result<Vec<u32>, _>>()?;
+                updates.push(nums);
             }
         }
+
+        if updates.is_empty() {
+            return Err(ParseError::end_of_input(input, "an update").into());
+        }
+        Ok(Self { dict, updates })
+    }
+
+    fn part_one(&self) -> u32 {
+        let (correct_updates, _) = self.filter_correct_updates();
+        Self::cal_mid_sum_up(&correct_updates)
+    }
+
+    fn part_two(&self) -> u32 {
+        let (_, incorrect_updates) = self.filter_correct_updates();
+        Self::cal_mid_sum_up(&incorrect_updates)
     }
 }
 
+common::impl_from_str!(Dataset);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(5, Dataset);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::manifest_path;
+
+    #[test]
+    fn test_sample() {
+        let data_set = Dataset::load_dataset(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(data_set.part_one(), 143);
+        assert_eq!(data_set.part_two(), 123);
+    }
 
     #[test]
     fn it_works() {
-        let mut filename = std::env::current_dir().unwrap();
-        filename.push("data.txt");
-        let mut data_set = Dataset::load_dataset(filename);
-        data_set.filter_correct_updates();
-        data_set.cal_mid_sum_up(Type::Correct);
-        data_set.cal_mid_sum_up(Type::Incorrect);
-        println!("{:?}", data_set.correct_mid_sum_up); //5509
-        println!("{:?}", data_set.incorrect_mid_sum_up); //4407
+        let data_set = Dataset::load_dataset(manifest_path!("data.txt")).unwrap();
+        assert_eq!(data_set.part_one(), 5509);
+        assert_eq!(data_set.part_two(), 4407);
     }
 }
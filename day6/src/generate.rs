@@ -0,0 +1,37 @@
+use std::collections::HashSet;
+
+use common::Rng;
+use grid::{Direction, Grid, Point};
+
+/// a `size` x `size` lab with some obstructions, and a guard who walks out of it eventually;
+/// maps where the guard would already be stuck in a loop are thrown away and drawn again
+pub fn generate(size: usize, seed: u64) -> String {
+    let size = size.max(2);
+    let mut rng = Rng::new(seed);
+    loop {
+        let mut map = Grid::filled(size, size, '.');
+        for position in map.positions().collect::<Vec<_>>() {
+            if rng.chance(10) {
+                map[position] = '#';
+            }
+        }
+        let start = (rng.below(size), rng.below(size));
+        map[start] = '^';
+        if leaves_the_map(&map, Point::from(start)) {
+            return map.to_string();
+        }
+    }
+}
+
+fn leaves_the_map(map: &Grid<char>, mut position: Point) -> bool {
+    let mut direction = Direction::Up;
+    let mut seen = HashSet::new();
+    while seen.insert((position, direction)) {
+        match map.get_point(position + direction) {
+            None => return true,
+            Some('#') => direction = direction.turn_right(),
+            Some(_) => position += direction,
+        }
+    }
+    false
+}
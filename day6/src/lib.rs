@@ -1,243 +1,215 @@
+pub mod generate;
+
 use std::{
-    clone,
-    collections::{HashMap, HashSet},
-    fs::File,
-    io::{BufRead, BufReader},
+    collections::HashSet,
+    io::{self, Write},
     path::PathBuf,
 };
 
-#[derive(Debug, Clone)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+use anyhow::Result;
+use common::{Cancelled, ParseError, Progress, Solution};
+use grid::{Animation, Direction, Grid, Point};
 
 #[derive(Debug, Clone)]
-struct LabMap {
-    map: Vec<Vec<char>>,
-    visited: HashSet<(usize, usize)>,
-    start_point: (usize, usize),
-    direction: Direction,
-    height: usize,
-    width: usize,
+pub struct LabMap {
+    map: Grid<char>,
+    start_point: Point,
 }
 
 impl LabMap {
-    fn load_dataset(filename: PathBuf) -> Self {
-        let file = File::open(filename).unwrap();
-        let reader = BufReader::new(file);
-        let mut map = Vec::new();
-        let mut start_row = 0;
-        let mut start_col = 0;
-
-        for (idx, line) in reader.lines().enumerate() {
-            let line: Vec<char> = line.unwrap().chars().collect();
-            if let Some(col) = line.iter().position(|&c| c == '^') {
-                //hard code here for my dataset is start as up
-                start_row = idx;
-                start_col = col;
-            }
-            map.push(line);
+    pub fn load_dataset(filename: PathBuf) -> Result<Self> {
+        Self::load(&filename)
+    }
+
+    /// one move of the guard: turn right in front of a '#', otherwise step forward;
+    /// `None` once the guard leaves the map
+    fn patrol(&self, position: Point, direction: Direction) -> Option<(Point, Direction)> {
+        let next = position + direction;
+        match self.map.get_point(next)? {
+            '#' => Some((position, direction.turn_right())),
+            _ => Some((next, direction)),
         }
-        Self {
-            height: map.len(),
-            width: map[0].len(),
-            map,
-            visited: HashSet::new(),
-            start_point: (start_row, start_col),
-            direction: Direction::Up,
+    }
+
+    fn walk(&self) -> Walk<'_> {
+        //hard code here for my dataset is start as up
+        Walk {
+            lab_map: self,
+            state: Some((self.start_point, Direction::Up)),
+            seen: HashSet::new(),
+            looped: false,
         }
     }
 
-    fn count_unique_position(&mut self) -> u32 {
-        let mut row = self.start_point.0 as i32;
-        let mut col = self.start_point.1 as i32;
-        let mut ret = 0;
-        while row >= 0 && (row as usize) < self.height && col >= 0 && (col as usize) < self.width {
-            match self.direction {
-                Direction::Up => {
-                    // go up until touch the map edge or match a '#'
-                    while row >= 0 && self.map[row as usize][col as usize] != '#' {
-                        if self.visited.insert((row as usize, col as usize)) {
-                            ret += 1;
-                        }
-                        row -= 1;
-                    }
-                    if row < 0 {
-                        break;
-                    } else {
-                        // turn right
-                        self.direction = Direction::Right;
-                        row += 1;
-                    }
-                }
-                Direction::Down => {
-                    while (row as usize) < self.height
-                        && self.map[row as usize][col as usize] != '#'
-                    {
-                        if self.visited.insert((row as usize, col as usize)) {
-                            ret += 1;
-                        }
-                        row += 1;
-                    }
-                    if (row as usize) >= self.height {
-                        break;
-                    } else {
-                        // turn right
-                        self.direction = Direction::Left;
-                        row -= 1;
-                    }
-                }
-                Direction::Left => {
-                    while col >= 0 && self.map[row as usize][col as usize] != '#' {
-                        if self.visited.insert((row as usize, col as usize)) {
-                            ret += 1;
-                        }
-                        col -= 1;
-                    }
-                    if col < 0 {
-                        break;
-                    } else {
-                        self.direction = Direction::Up;
-                        col += 1
-                    }
-                }
-                Direction::Right => {
-                    while (col as usize) < self.width && self.map[row as usize][col as usize] != '#'
-                    {
-                        if self.visited.insert((row as usize, col as usize)) {
-                            ret += 1;
-                        }
-                        col += 1;
-                    }
-                    if (col as usize) >= self.width {
-                        break;
-                    } else {
-                        self.direction = Direction::Down;
-                        col -= 1;
-                    }
-                }
+    /// the positions the guard visits before it leaves, or before it walks the same loop again
+    fn count_unique_position(&self) -> u32 {
+        let visited: HashSet<Point> = self.walk().map(|(position, _)| position).collect();
+        visited.len() as u32
+    }
+
+    /// the walk of part one, one frame per move, with the visited positions marked 'X'
+    pub fn animate(&self, animation: &mut Animation<impl Write>) -> io::Result<()> {
+        let mut map = self.map.clone();
+        let mut walk = self.walk();
+        let mut visited: HashSet<Point> = HashSet::new();
+        let mut last = self.start_point;
+        for (position, direction) in walk.by_ref() {
+            visited.insert(position);
+            map[last] = 'X';
+            map[position] = direction.to_char();
+            last = position;
+            let status = format!(
+                "step {}, {} positions visited",
+                animation.frames(),
+                visited.len()
+            );
+            if !animation.frame(&map, &status)? {
+                return Ok(());
             }
         }
-        ret
+        map[last] = 'X';
+        let end = if walk.looped {
+            "the guard walks in a loop"
+        } else {
+            "the guard left"
+        };
+        animation.finish(
+            &map,
+            &format!("{}, {} positions visited", end, visited.len()),
+        )
+    }
+
+    /// the map once the guard left or walked its whole loop, with the visited positions
+    /// marked 'X'
+    pub fn picture(&self) -> Grid<char> {
+        let mut map = self.map.clone();
+        for (position, _) in self.walk() {
+            map[position] = 'X';
+        }
+        map
     }
 
-    fn count_possilbe_to_make_loop(&mut self) -> u32 {
+    /// one progress tick per cell tried
+    fn count_possilbe_to_make_loop(&self, progress: &mut Progress) -> Result<u32, Cancelled> {
+        // try the obstructions on a scratch copy, so the loaded map stays untouched
+        let mut lab_map = self.clone();
         let mut ret = 0;
-        for row in 0..self.height {
-            for col in 0..self.width {
-                if self.map[row][col] == '#' || self.map[row][col] == '^' {
+        let cells = (self.map.height() * self.map.width()) as u64;
+        for row in 0..self.map.height() {
+            for col in 0..self.map.width() {
+                progress.tick(Some(cells))?;
+                if lab_map.map[(row, col)] == '#' || lab_map.map[(row, col)] == '^' {
                     continue;
                 } else {
-                    self.map[row][col] = '#';
-                    if self.is_loop() {
+                    lab_map.map[(row, col)] = '#';
+                    if lab_map.is_loop() {
                         ret += 1;
                     }
-                    self.direction = Direction::Up;
-                    self.map[row][col] = '.';
+                    lab_map.map[(row, col)] = '.';
                 }
             }
         }
-        ret
-    }
-    // check if pass a postion 5 times, equavilent to same direction passing twice
-    fn is_loop(&mut self) -> bool {
-        let mut count: HashMap<(i32, i32), i32> = HashMap::new();
-        let mut row = self.start_point.0 as i32;
-        let mut col = self.start_point.1 as i32;
-
-        while row >= 0 && (row as usize) < self.height && col >= 0 && (col as usize) < self.width {
-            match self.direction {
-                Direction::Up => {
-                    // go up until touch the map edge or match a '#'
-                    while row >= 0 && self.map[row as usize][col as usize] != '#' {
-                        *count.entry((row, col)).or_insert(0) += 1;
-                        if count.get(&(row, col)) >= Some(&5) {
-                            return true;
-                        }
-                        row -= 1;
-                    }
-                    if row < 0 {
-                        break;
-                    } else {
-                        // turn right
-                        self.direction = Direction::Right;
-                        // step back and deduct the times of passing this position, since next move will start from this position and add time of passing again
-                        row += 1;
-                        *count.get_mut(&(row, col)).unwrap() -= 1;
-                    }
-                }
-                Direction::Down => {
-                    while (row as usize) < self.height
-                        && self.map[row as usize][col as usize] != '#'
-                    {
-                        *count.entry((row, col)).or_insert(0) += 1;
-                        if count.get(&(row, col)) >= Some(&5) {
-                            return true;
-                        }
-                        row += 1;
-                    }
-                    if (row as usize) >= self.height {
-                        break;
-                    } else {
-                        // turn right
-                        self.direction = Direction::Left;
-                        row -= 1;
-                        *count.get_mut(&(row, col)).unwrap() -= 1;
-                    }
-                }
-                Direction::Left => {
-                    while col >= 0 && self.map[row as usize][col as usize] != '#' {
-                        *count.entry((row, col)).or_insert(0) += 1;
-                        if count.get(&(row, col)) >= Some(&5) {
-                            return true;
-                        }
-                        col -= 1;
-                    }
-                    if col < 0 {
-                        break;
-                    } else {
-                        self.direction = Direction::Up;
-                        col += 1;
-                        *count.get_mut(&(row, col)).unwrap() -= 1;
-                    }
-                }
-                Direction::Right => {
-                    while (col as usize) < self.width && self.map[row as usize][col as usize] != '#'
-                    {
-                        *count.entry((row, col)).or_insert(0) += 1;
-                        if count.get(&(row, col)) >= Some(&5) {
-                            return true;
-                        }
-                        col += 1;
-                    }
-                    if (col as usize) >= self.width {
-                        break;
-                    } else {
-                        self.direction = Direction::Down;
-                        col -= 1;
-                        *count.get_mut(&(row, col)).unwrap() -= 1;
-                    }
-                }
-            }
+        Ok(ret)
+    }
+    fn is_loop(&self) -> bool {
+        let mut walk = self.walk();
+        walk.by_ref().for_each(drop);
+        walk.looped
+    }
+}
+
+/// The positions and directions of the guard from the start, until it leaves the map or
+/// walks in a loop
+struct Walk<'a> {
+    lab_map: &'a LabMap,
+    state: Option<(Point, Direction)>,
+    seen: HashSet<(Point, Direction)>,
+    /// the walk ended on a state it had been in
+    looped: bool,
+}
+
+impl Iterator for Walk<'_> {
+    type Item = (Point, Direction);
+
+    // the guard is in a loop once it stands on the same position facing the same direction twice
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.state?;
+        if !self.seen.insert(current) {
+            self.state = None;
+            self.looped = true;
+            return None;
         }
-        return false;
+        self.state = self.lab_map.patrol(current.0, current.1);
+        Some(current)
+    }
+}
+
+impl Solution for LabMap {
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let map = Grid::parse(input).map_err(ParseError::from)?;
+        let start_point = map
+            .position(|&c| c == '^')
+            .map(Point::from)
+            .ok_or_else(|| ParseError::new(1, 1, "a guard '^' on the map", "no guard"))?;
+        Ok(Self { map, start_point })
+    }
+
+    fn part_one(&self) -> u32 {
+        self.count_unique_position()
+    }
+
+    fn part_two(&self) -> u32 {
+        // nothing cancels without a limit
+        self.part_two_with(&mut Progress::default()).unwrap()
+    }
+
+    fn part_two_with(&self, progress: &mut Progress) -> Result<u32, Cancelled> {
+        self.count_possilbe_to_make_loop(progress)
     }
 }
 
+common::impl_from_str!(LabMap);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(6, LabMap);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::manifest_path;
 
     #[test]
-    fn it_works() {
-        let mut filename = std::env::current_dir().unwrap();
-        filename.push("data.txt");
-        let mut lab_map = LabMap::load_dataset(filename);
+    fn test_sample() {
+        let lab_map = LabMap::load_dataset(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(lab_map.part_one(), 41);
+        assert_eq!(lab_map.part_two(), 6);
+    }
 
-        println!("{:?}", lab_map.count_unique_position()); //5534
-        println!("{:?}", lab_map.count_possilbe_to_make_loop()); //2262
+    #[test]
+    fn test_cancel() {
+        let lab_map = LabMap::load_dataset(manifest_path!("data_test.txt")).unwrap();
+        let mut progress = Progress::default().with_max_iterations(10);
+        assert_eq!(
+            lab_map.part_two_with(&mut progress),
+            Err(Cancelled::MaxIterations(10))
+        );
+    }
+
+    #[test]
+    fn test_loop() {
+        let boxed_in: LabMap = "###\n#^#\n###\n".parse().unwrap();
+        assert_eq!(boxed_in.part_one(), 1);
+        let looping: LabMap = ".#..\n...#\n#^..\n..#.\n".parse().unwrap();
+        assert_eq!(looping.part_one(), 4);
+        assert_eq!(looping.picture().to_string(), ".#..\n.XX#\n#XX.\n..#.\n");
+    }
+
+    #[test]
+    fn it_works() {
+        let lab_map = LabMap::load_dataset(manifest_path!("data.txt")).unwrap();
+        assert_eq!(lab_map.part_one(), 5534);
+        assert_eq!(lab_map.part_two(), 2262);
     }
 }
@@ -0,0 +1,33 @@
+use common::Rng;
+
+/// `size` equations of 2 to 6 numbers below 1000, so even concatenating all of them fits in u64;
+/// about half of the test values can be made with +, * and ||
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut ret = String::new();
+    for _ in 0..size {
+        let numbers: Vec<u64> = (0..rng.between(2, 6))
+            .map(|_| {
+                if rng.chance(70) {
+                    rng.between(1, 99) as u64
+                } else {
+                    rng.between(1, 999) as u64
+                }
+            })
+            .collect();
+        let mut value = numbers[0];
+        for number in &numbers[1..] {
+            value = match rng.below(3) {
+                0 => value + number,
+                1 => value * number,
+                _ => format!("{}{}", value, number).parse().unwrap(),
+            };
+        }
+        if rng.chance(50) {
+            value += 1;
+        }
+        let numbers: Vec<String> = numbers.iter().map(|number| number.to_string()).collect();
+        ret.push_str(&format!("{}: {}\n", value, numbers.join(" ")));
+    }
+    ret
+}
@@ -1,52 +1,35 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{BufRead, BufReader},
-    path::PathBuf,
-};
+pub mod generate;
+
+use std::{collections::HashSet, path::PathBuf};
+
+use anyhow::Result;
+use common::{parse_field, CrossCheck, Disagreement, ParseError, Solution as _};
 
 #[derive(Debug, Clone)]
-struct Solution {
+pub struct Solution {
     dataset: Vec<(u64, Vec<u64>)>,
-    answer_part_one: u64,
-    answer_part_two: u64,
 }
 
 impl Solution {
-    fn load_data(filename: PathBuf) -> Self {
-        let file = File::open(filename).unwrap();
-        let reader = BufReader::new(file);
-        let mut dataset = Vec::new();
-        for line in reader.lines() {
-            let line = line.unwrap();
-            let parts = line.split(":").collect::<Vec<&str>>();
-            let answer = parts[0].parse::<u64>().unwrap();
-            let components: Vec<u64> = parts[1]
-                .to_string()
-                .split_whitespace()
-                .into_iter()
-                .map(|num| num.parse::<u64>().unwrap())
-                .collect();
-            dataset.push((answer, components));
-        }
-        Self {
-            dataset,
-            answer_part_one: 0,
-            answer_part_two: 0,
-        }
+    pub fn load_data(filename: PathBuf) -> Result<Self> {
+        Self::load(&filename)
     }
 
-    fn part_a(&mut self) {
+    /// the test values are u64s, so their sum over every line needs a u128
+    fn part_a(&self) -> u128 {
+        let mut ret = 0;
         for (answer, component) in self.dataset.iter() {
             let mut result_set = HashSet::new();
             self.strict_add_or_mul_from_left_to_right(component, component[0], 1, &mut result_set);
             if result_set.contains(answer) {
-                self.answer_part_one += answer;
+                ret += *answer as u128;
             }
         }
+        ret
     }
 
-    fn part_b(&mut self) {
+    fn part_b(&self) -> u128 {
+        let mut ret = 0;
         for (answer, component) in self.dataset.iter() {
             let mut result_set = HashSet::new();
             self.strict_add_or_mul_or_concat_from_left_to_right(
@@ -56,9 +39,10 @@ impl Solution {
                 &mut result_set,
             );
             if result_set.contains(answer) {
-                self.answer_part_two += answer;
+                ret += *answer as u128;
             }
         }
+        ret
     }
 
     // this is wrong
@@ -67,7 +51,7 @@ impl Solution {
     //  1+2+3, (1*2)+3, 1*(2+3), (1+2)*3, 1+(2*3) and 1*2*3
     // but strict from left to right are:
     // 1+2+3, (1*2)+3, (1+2)*3 and 1*2*3
-    fn naive_get_all_possible_answer(&self, component: &[u64]) -> HashSet<u64> {
+    pub fn naive_get_all_possible_answer(&self, component: &[u64]) -> HashSet<u64> {
         if component.len() == 1 {
             let mut ret = HashSet::new();
             ret.insert(component[0]);
@@ -82,12 +66,12 @@ impl Solution {
             let right_results = self.naive_get_all_possible_answer(right);
             for left_result in &left_results {
                 for right_result in &right_results {
-                    result.insert(left_result + right_result);
-                    result.insert(left_result * right_result);
+                    result.extend(left_result.checked_add(*right_result));
+                    result.extend(left_result.checked_mul(*right_result));
                 }
             }
         }
-        return result;
+        result
     }
 
     fn strict_add_or_mul_from_left_to_right(
@@ -101,21 +85,16 @@ impl Solution {
             result_set.insert(prev_res);
             return;
         }
-        // add current number
-        self.strict_add_or_mul_from_left_to_right(
-            component,
-            prev_res + component[cur_idx],
-            cur_idx + 1,
-            result_set,
-        );
-        // mul current number
-        self.strict_add_or_mul_from_left_to_right(
-            component,
-            prev_res * component[cur_idx],
-            cur_idx + 1,
-            result_set,
-        );
-        // concate
+        // a result past u64 is too big for any test value, so that branch is pruned
+        let results = [
+            // add current number
+            prev_res.checked_add(component[cur_idx]),
+            // mul current number
+            prev_res.checked_mul(component[cur_idx]),
+        ];
+        for result in results.into_iter().flatten() {
+            self.strict_add_or_mul_from_left_to_right(component, result, cur_idx + 1, result_set);
+        }
     }
 
     fn strict_add_or_mul_or_concat_from_left_to_right(
@@ -126,47 +105,140 @@ impl Solution {
         result_set: &mut HashSet<u64>,
     ) {
         let concat_number = |num_left: u64, num_right: u64| {
-            let num = format!("{}{}", num_left, num_right);
-            num.parse::<u64>().unwrap()
+            let digits = num_right.checked_ilog10().unwrap_or(0) + 1;
+            num_left
+                .checked_mul(10u64.checked_pow(digits)?)?
+                .checked_add(num_right)
         };
         if cur_idx == component.len() {
             result_set.insert(prev_res);
             return;
         }
-        // add
-        self.strict_add_or_mul_or_concat_from_left_to_right(
-            component,
-            prev_res + component[cur_idx],
-            cur_idx + 1,
-            result_set,
-        );
-        // mul
-        self.strict_add_or_mul_or_concat_from_left_to_right(
-            component,
-            prev_res * component[cur_idx],
-            cur_idx + 1,
-            result_set,
-        );
-        // concat
-        self.strict_add_or_mul_or_concat_from_left_to_right(
-            component,
+        // a result past u64 is too big for any test value, so that branch is pruned
+        let results = [
+            prev_res.checked_add(component[cur_idx]),
+            prev_res.checked_mul(component[cur_idx]),
             concat_number(prev_res, component[cur_idx]),
-            cur_idx + 1,
-            result_set,
-        );
+        ];
+        for result in results.into_iter().flatten() {
+            self.strict_add_or_mul_or_concat_from_left_to_right(
+                component,
+                result,
+                cur_idx + 1,
+                result_set,
+            );
+        }
     }
 }
+
+impl common::Solution for Solution {
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut dataset = Vec::new();
+        for (idx, line) in input.lines().enumerate() {
+            let (answer, components) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::end_of_line(idx + 1, line, "':'"))?;
+            let answer = parse_field(idx + 1, line, answer, "a test value")?;
+            let components = components
+                .split_whitespace()
+                .map(|num| parse_field(idx + 1, line, num, "a number"))
+                .collect::<Result<Vec<u64>, _>>()?;
+            if components.is_empty() {
+                return Err(ParseError::end_of_line(idx + 1, line, "a number").into());
+            }
+            dataset.push((answer, components));
+        }
+        if dataset.is_empty() {
+            return Err(ParseError::end_of_input(input, "an equation").into());
+        }
+        Ok(Self { dataset })
+    }
+
+    fn part_one(&self) -> u128 {
+        self.part_a()
+    }
+
+    fn part_two(&self) -> u128 {
+        self.part_b()
+    }
+}
+
+impl CrossCheck for Solution {
+    /// the naive search brackets the numbers every way, and left to right is one of them, so
+    /// every strict result has to be a naive one too
+    fn cross_check(&self) -> Vec<Disagreement> {
+        let mut ret = Vec::new();
+        for (idx, (answer, component)) in self.dataset.iter().enumerate() {
+            let naive = self.naive_get_all_possible_answer(component);
+            let mut strict = HashSet::new();
+            self.strict_add_or_mul_from_left_to_right(component, component[0], 1, &mut strict);
+            let mut strict: Vec<u64> = strict.into_iter().collect();
+            strict.sort_unstable();
+            let in_naive = strict
+                .iter()
+                .copied()
+                .filter(|result| naive.contains(result))
+                .collect();
+            ret.extend(Disagreement::compare(
+                "strict left to right within naive_get_all_possible_answer",
+                format_args!("line {}, test value {}", idx + 1, answer),
+                in_naive,
+                strict,
+            ));
+        }
+        ret
+    }
+}
+
+common::impl_from_str!(Solution);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(7, Solution);
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use common::manifest_path;
+
+    #[test]
+    fn test_sample() {
+        let solution = Solution::load_data(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(solution.part_one(), 3749);
+        assert_eq!(solution.part_two(), 11387);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let solution: Solution = "18446744073709551615: 9 9".parse().unwrap();
+        assert_eq!(solution.part_one(), 0);
+        assert_eq!(solution.part_two(), 0);
+        // 18446744073709551615 * 1 fits, its sum and concatenation do not
+        let solution: Solution = "18446744073709551615: 18446744073709551615 1"
+            .parse()
+            .unwrap();
+        assert_eq!(solution.part_one(), u64::MAX as u128);
+        assert_eq!(solution.part_two(), u64::MAX as u128);
+        // two of them are past a u64
+        let solution: Solution = "18446744073709551615: 18446744073709551615 1\n\
+            18446744073709551614: 18446744073709551614"
+            .parse()
+            .unwrap();
+        assert_eq!(solution.part_one(), 2 * u64::MAX as u128 - 1);
+        assert_eq!(solution.part_two(), 2 * u64::MAX as u128 - 1);
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let solution: Solution = generate::generate(50, 7).parse().unwrap();
+        assert_eq!(solution.cross_check(), vec![]);
+    }
+
     #[test]
     fn test() {
-        let mut filename = std::env::current_dir().unwrap();
-        filename.push("data.txt");
-        let mut solution = Solution::load_data(filename);
-        solution.part_a();
-        println!("{:?}", solution.answer_part_one); //1399219271639
-        solution.part_b();
-        println!("{:?}", solution.answer_part_two); //275791737999003
+        let solution = Solution::load_data(manifest_path!("data.txt")).unwrap();
+        assert_eq!(solution.part_one(), 1399219271639);
+        assert_eq!(solution.part_two(), 275791737999003);
     }
 }
@@ -0,0 +1,19 @@
+use common::Rng;
+use grid::Grid;
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// a `size` x `size` map with about `size` antennas of a handful of frequencies
+pub fn generate(size: usize, seed: u64) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let frequencies: Vec<char> = (0..rng.between(1, 6))
+        .map(|_| *rng.pick(FREQUENCIES) as char)
+        .collect();
+    let mut map = Grid::filled(size, size, '.');
+    for _ in 0..size {
+        let position = (rng.below(size), rng.below(size));
+        map[position] = *rng.pick(&frequencies);
+    }
+    map.to_string()
+}
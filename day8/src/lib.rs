@@ -1,64 +1,53 @@
+pub mod generate;
+
 use std::{
     collections::{hash_map::Entry, HashMap, HashSet},
-    fs::File,
-    io::{BufRead, BufReader},
     path::PathBuf,
 };
 
+use anyhow::Result;
+use common::{ParseError, Solution};
+use grid::Grid;
+
 #[derive(Debug, Clone)]
-struct SignalMap {
+pub struct SignalMap {
     signal_point: HashMap<u8, Vec<(i32, i32)>>,
-    antinodes: HashSet<(i32, i32)>,
-    antinodes_without_limit: HashSet<(i32, i32)>,
     map_height: u32,
     map_width: u32,
 }
 
 impl SignalMap {
-    fn load_data(filename: PathBuf) -> Self {
-        let file = File::open(filename).unwrap();
-        let reader = BufReader::new(file);
-        let mut signal_point: HashMap<u8, Vec<(i32, i32)>> = HashMap::new();
-        let mut row = 0;
-        let mut width = 0;
-        for line in reader.lines() {
-            let line = line.unwrap();
-            let mut col = 0;
-            for char in line.as_bytes() {
-                if char.is_ascii_digit() || char.is_ascii_alphabetic() {
-                    match signal_point.entry(char.to_owned()) {
-                        Entry::Occupied(mut entry) => {
-                            entry.get_mut().push((row, col));
-                        }
-                        Entry::Vacant(entry) => {
-                            let mut position_vec = Vec::new();
-                            position_vec.push((row, col));
-                            entry.insert(position_vec);
-                        }
-                    }
-                }
-                col += 1;
-            }
-            width = col;
-            row += 1;
+    pub fn load_data(filename: PathBuf) -> Result<Self> {
+        Self::load(&filename)
+    }
+
+    /// the antinodes of part one as '#', under the antennas which keep their frequency
+    pub fn picture(&self) -> Grid<char> {
+        let mut map = Grid::filled(self.map_height as usize, self.map_width as usize, '.');
+        for (row, col) in self.detect_antinodes() {
+            map[(row as usize, col as usize)] = '#';
         }
-        Self {
-            signal_point,
-            antinodes: HashSet::new(),
-            antinodes_without_limit: HashSet::new(),
-            map_height: row as u32,
-            map_width: width as u32,
+        for (frequency, points) in &self.signal_point {
+            for (row, col) in points {
+                map[(*row as usize, *col as usize)] = *frequency as char;
+            }
         }
+        map
     }
 
-    fn detect_antinodes(&mut self) {
-        let signal_point = self.signal_point.clone();
-        for (_, point_vec) in signal_point.iter() {
-            self.detect_antinodes_for_one_frequency(point_vec);
+    fn detect_antinodes(&self) -> HashSet<(i32, i32)> {
+        let mut antinodes = HashSet::new();
+        for (_, point_vec) in self.signal_point.iter() {
+            self.detect_antinodes_for_one_frequency(point_vec, &mut antinodes);
         }
+        antinodes
     }
 
-    fn detect_antinodes_for_one_frequency(&mut self, point_vec: &[(i32, i32)]) {
+    fn detect_antinodes_for_one_frequency(
+        &self,
+        point_vec: &[(i32, i32)],
+        antinodes: &mut HashSet<(i32, i32)>,
+    ) {
         if point_vec.len() < 2 {
             return;
         }
@@ -73,68 +62,73 @@ impl SignalMap {
                     let antinode_a_row = point_a.0 - row_diff;
                     let antinode_a_col = point_a.1 - col_diff;
                     if antinode_a_row >= 0 && antinode_a_col >= 0 {
-                        self.antinodes.insert((antinode_a_row, antinode_a_col));
+                        antinodes.insert((antinode_a_row, antinode_a_col));
                     }
                     let antinode_b_row = point_b.0 + row_diff;
                     let antinode_b_col = point_b.1 + col_diff;
                     if antinode_b_row < self.map_height as i32
                         && antinode_b_col < self.map_width as i32
                     {
-                        self.antinodes.insert((antinode_b_row, antinode_b_col));
+                        antinodes.insert((antinode_b_row, antinode_b_col));
                     }
                 } else if point_a.0 <= point_b.0 && point_a.1 >= point_b.1 {
                     // a is on top right of b
                     let antinode_a_row = point_a.0 - row_diff;
                     let antinode_a_col = point_a.1 + col_diff;
                     if antinode_a_row >= 0 && antinode_a_col < self.map_width as i32 {
-                        self.antinodes.insert((antinode_a_row, antinode_a_col));
+                        antinodes.insert((antinode_a_row, antinode_a_col));
                     }
                     let antinode_b_row = point_b.0 + row_diff;
                     let antinode_b_col = point_b.1 - col_diff;
                     if antinode_b_row < self.map_height as i32 && antinode_b_col >= 0 {
-                        self.antinodes.insert((antinode_b_row, antinode_b_col));
+                        antinodes.insert((antinode_b_row, antinode_b_col));
                     }
                 } else if point_a.0 >= point_b.0 && point_a.1 <= point_b.1 {
                     // a is on down left of b
                     let antinode_a_row = point_a.0 + row_diff;
                     let antinode_a_col = point_a.1 - col_diff;
                     if antinode_a_row < self.map_height as i32 && antinode_a_col >= 0 {
-                        self.antinodes.insert((antinode_a_row, antinode_a_col));
+                        antinodes.insert((antinode_a_row, antinode_a_col));
                     }
                     let antinode_b_row = point_b.0 - row_diff;
                     let antinode_b_col = point_b.1 + col_diff;
                     if antinode_b_row >= 0 && antinode_b_col < self.map_width as i32 {
-                        self.antinodes.insert((antinode_b_row, antinode_b_col));
+                        antinodes.insert((antinode_b_row, antinode_b_col));
                     }
-                } else if point_a.0 >= point_b.0 && point_b.1 >= point_b.1 {
+                } else if point_a.0 >= point_b.0 && point_a.1 >= point_b.1 {
                     // a is on down right of b
                     let antinode_a_row = point_a.0 + row_diff;
                     let antinode_a_col = point_a.1 + col_diff;
                     if antinode_a_row < self.map_height as i32
                         && antinode_a_col < self.map_width as i32
                     {
-                        self.antinodes.insert((antinode_a_row, antinode_a_col));
+                        antinodes.insert((antinode_a_row, antinode_a_col));
                     }
                     let antinode_b_row = point_b.0 - row_diff;
                     let antinode_b_col = point_b.1 - col_diff;
                     if antinode_b_row >= 0 && antinode_b_col >= 0 {
-                        self.antinodes.insert((antinode_b_row, antinode_b_col));
+                        antinodes.insert((antinode_b_row, antinode_b_col));
                     }
                 }
             }
         }
     }
 
-    fn detect_antinodes_without_distance_limit(&mut self) {
-        let signal_points = self.signal_point.clone();
-        for (_, point_vec) in signal_points.iter() {
-            self.detect_antinodes_without_distance_limit_for_one_requency(point_vec);
+    fn detect_antinodes_without_distance_limit(&self) -> HashSet<(i32, i32)> {
+        let mut antinodes = HashSet::new();
+        for (_, point_vec) in self.signal_point.iter() {
+            self.detect_antinodes_without_distance_limit_for_one_requency(
+                point_vec,
+                &mut antinodes,
+            );
         }
+        antinodes
     }
 
     fn detect_antinodes_without_distance_limit_for_one_requency(
-        &mut self,
+        &self,
         point_vec: &[(i32, i32)],
+        antinodes: &mut HashSet<(i32, i32)>,
     ) {
         if point_vec.len() < 2 {
             return;
@@ -145,15 +139,14 @@ impl SignalMap {
                 let point_b = point_vec[fast_ptr];
                 let row_diff = (point_a.0 - point_b.0).abs();
                 let col_diff = (point_a.1 - point_b.1).abs();
-                self.antinodes_without_limit.insert((point_a.0, point_a.1));
-                self.antinodes_without_limit.insert((point_b.0, point_b.1));
+                antinodes.insert((point_a.0, point_a.1));
+                antinodes.insert((point_b.0, point_b.1));
                 if point_a.0 <= point_b.0 && point_a.1 <= point_b.1 {
                     // a is on top left of b
                     let mut antinode_a_row = point_a.0 - row_diff;
                     let mut antinode_a_col = point_a.1 - col_diff;
                     while antinode_a_row >= 0 && antinode_a_col >= 0 {
-                        self.antinodes_without_limit
-                            .insert((antinode_a_row, antinode_a_col));
+                        antinodes.insert((antinode_a_row, antinode_a_col));
                         antinode_a_row -= row_diff;
                         antinode_a_col -= col_diff;
                     }
@@ -162,8 +155,7 @@ impl SignalMap {
                     while antinode_b_row < self.map_height as i32
                         && antinode_b_col < self.map_width as i32
                     {
-                        self.antinodes_without_limit
-                            .insert((antinode_b_row, antinode_b_col));
+                        antinodes.insert((antinode_b_row, antinode_b_col));
                         antinode_b_row += row_diff;
                         antinode_b_col += col_diff;
                     }
@@ -172,16 +164,14 @@ impl SignalMap {
                     let mut antinode_a_row = point_a.0 - row_diff;
                     let mut antinode_a_col = point_a.1 + col_diff;
                     while antinode_a_row >= 0 && antinode_a_col < self.map_width as i32 {
-                        self.antinodes_without_limit
-                            .insert((antinode_a_row, antinode_a_col));
+                        antinodes.insert((antinode_a_row, antinode_a_col));
                         antinode_a_row -= row_diff;
                         antinode_a_col += col_diff;
                     }
                     let mut antinode_b_row = point_b.0 + row_diff;
                     let mut antinode_b_col = point_b.1 - col_diff;
                     while antinode_b_row < self.map_height as i32 && antinode_b_col >= 0 {
-                        self.antinodes_without_limit
-                            .insert((antinode_b_row, antinode_b_col));
+                        antinodes.insert((antinode_b_row, antinode_b_col));
                         antinode_b_row += row_diff;
                         antinode_b_col -= col_diff;
                     }
@@ -190,36 +180,32 @@ impl SignalMap {
                     let mut antinode_a_row = point_a.0 + row_diff;
                     let mut antinode_a_col = point_a.1 - col_diff;
                     while antinode_a_row < self.map_height as i32 && antinode_a_col >= 0 {
-                        self.antinodes_without_limit
-                            .insert((antinode_a_row, antinode_a_col));
+                        antinodes.insert((antinode_a_row, antinode_a_col));
                         antinode_a_row += row_diff;
                         antinode_a_col -= col_diff;
                     }
                     let mut antinode_b_row = point_b.0 - row_diff;
                     let mut antinode_b_col = point_b.1 + col_diff;
                     while antinode_b_row >= 0 && antinode_b_col < self.map_width as i32 {
-                        self.antinodes_without_limit
-                            .insert((antinode_b_row, antinode_b_col));
+                        antinodes.insert((antinode_b_row, antinode_b_col));
                         antinode_b_row -= row_diff;
                         antinode_b_col += col_diff;
                     }
-                } else if point_a.0 >= point_b.0 && point_b.1 >= point_b.1 {
+                } else if point_a.0 >= point_b.0 && point_a.1 >= point_b.1 {
                     // a is on down right of b
                     let mut antinode_a_row = point_a.0 + row_diff;
                     let mut antinode_a_col = point_a.1 + col_diff;
                     while antinode_a_row < self.map_height as i32
                         && antinode_a_col < self.map_width as i32
                     {
-                        self.antinodes_without_limit
-                            .insert((antinode_a_row, antinode_a_col));
+                        antinodes.insert((antinode_a_row, antinode_a_col));
                         antinode_a_row += row_diff;
                         antinode_a_col += col_diff;
                     }
                     let mut antinode_b_row = point_b.0 - row_diff;
                     let mut antinode_b_col = point_b.1 - col_diff;
                     while antinode_b_row >= 0 && antinode_b_col >= 0 {
-                        self.antinodes_without_limit
-                            .insert((antinode_b_row, antinode_b_col));
+                        antinodes.insert((antinode_b_row, antinode_b_col));
                         antinode_b_row -= row_diff;
                         antinode_b_col -= col_diff;
                     }
@@ -229,12 +215,59 @@ impl SignalMap {
     }
 }
 
-fn main() {
-    let mut filename = std::env::current_dir().unwrap();
-    filename = filename.join("day8/data.txt");
-    let mut singal_map = SignalMap::load_data(filename);
-    singal_map.detect_antinodes();
-    println!("{:?}", singal_map.antinodes.len()); //293
-    singal_map.detect_antinodes_without_distance_limit();
-    println!("{:?}", singal_map.antinodes_without_limit.len());
+impl Solution for SignalMap {
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        // '#' only shows up in the examples, where it marks the antinodes
+        let map = Grid::parse_with(input, |c| {
+            (c.is_ascii_alphanumeric() || c == '.' || c == '#').then_some(c as u8)
+        })
+        .map_err(ParseError::from)?;
+        let mut signal_point: HashMap<u8, Vec<(i32, i32)>> = HashMap::new();
+        for ((row, col), char) in map.iter() {
+            if char.is_ascii_alphanumeric() {
+                let point = (row as i32, col as i32);
+                match signal_point.entry(*char) {
+                    Entry::Occupied(mut entry) => {
+                        entry.get_mut().push(point);
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(vec![point]);
+                    }
+                }
+            }
+        }
+        Ok(Self {
+            signal_point,
+            map_height: map.height() as u32,
+            map_width: map.width() as u32,
+        })
+    }
+
+    fn part_one(&self) -> usize {
+        self.detect_antinodes().len()
+    }
+
+    fn part_two(&self) -> usize {
+        self.detect_antinodes_without_distance_limit().len()
+    }
+}
+
+common::impl_from_str!(SignalMap);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(8, SignalMap);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::manifest_path;
+
+    #[test]
+    fn test_sample() {
+        let signal_map = SignalMap::load_data(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(signal_map.part_one(), 14);
+        assert_eq!(signal_map.part_two(), 34);
+    }
 }
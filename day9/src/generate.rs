@@ -0,0 +1,16 @@
+use common::Rng;
+
+/// a disk map of `size` digits (at least 1), starting and ending with a file
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let len = size.max(1) | 1;
+    let mut ret: String = (0..len)
+        .map(|idx| {
+            // files have at least one block, free spaces can be empty
+            let low = if idx % 2 == 0 { 1 } else { 0 };
+            char::from(b'0' + rng.between(low, 9) as u8)
+        })
+        .collect();
+    ret.push('\n');
+    ret
+}
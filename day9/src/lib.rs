@@ -0,0 +1,262 @@
+pub mod generate;
+
+use std::{collections::VecDeque, path::PathBuf};
+
+use anyhow::Result;
+use common::{ParseError, Solution as _};
+
+#[derive(Debug, Clone)]
+pub struct Solution {
+    origin: Vec<i16>,
+}
+
+#[derive(Debug, Clone)]
+struct FileAndEmptySlot {
+    empty_queue: VecDeque<(i32, i32)>,
+    file_queue: VecDeque<(String, i32, i32)>,
+}
+
+impl Solution {
+    pub fn load_dataset(filename: PathBuf) -> Result<Self> {
+        Self::load(&filename)
+    }
+
+    fn convert(&self) -> Vec<String> {
+        let mut converted = Vec::new();
+        let mut file_id = 0;
+        let mut file_ptr = 0;
+        let mut empty_ptr = 1;
+        while file_ptr < self.origin.len() {
+            let file_repeats = self.origin[file_ptr];
+            for _ in 0..file_repeats {
+                converted.push(file_id.to_string());
+            }
+            // in case there is no digit to represent empty space
+            if empty_ptr < self.origin.len() {
+                let empty_repeats = self.origin[empty_ptr];
+                for _ in 0..empty_repeats {
+                    converted.push(".".to_string());
+                }
+            }
+            file_ptr += 2;
+            empty_ptr += 2;
+            file_id += 1;
+        }
+        converted
+    }
+
+    fn compact(converted: &[String]) -> Vec<String> {
+        let mut compacted = Vec::new();
+        let mut head_ptr = 0;
+        // a disk map of empty files only has no block to move
+        let Some(mut tail_ptr) = converted.len().checked_sub(1) else {
+            return compacted;
+        };
+
+        // the last number might not need to be move from back to head
+        // like 00998111888277733364465555.66.............
+        // after move the 2nd 6 in front of the 1st 6, the origin 1st 6 do not need to be move
+        // that is why use head_ptr<=tail_ptr instead of head_ptr<tail_ptr
+        while head_ptr <= tail_ptr {
+            // move to a empty slot
+            while head_ptr <= tail_ptr && converted[head_ptr] != "." {
+                compacted.push(converted[head_ptr].clone());
+                head_ptr += 1;
+            }
+            // move to a file slot
+            while head_ptr <= tail_ptr && converted[tail_ptr] == "." {
+                match tail_ptr.checked_sub(1) {
+                    Some(prev) => tail_ptr = prev,
+                    None => return compacted,
+                }
+            }
+            while head_ptr <= tail_ptr && converted[tail_ptr] != "." && converted[head_ptr] == "." {
+                compacted.push(converted[tail_ptr].clone());
+                tail_ptr -= 1;
+                head_ptr += 1;
+            }
+        }
+        compacted
+    }
+
+    fn cal_check_sum(compacted: &[String]) -> u64 {
+        let mut check_sum = 0;
+        let mut idx = 0;
+        while idx < compacted.len() {
+            let number: u64 = compacted[idx].parse().unwrap();
+            check_sum += (idx as u64) * number;
+            idx += 1;
+        }
+        check_sum
+    }
+
+    // scan the converted vec from tail to head
+    // build empty slot as Queue<(start_idx, length)>
+    // build file slot as Queue<(String, length, start_idx)>
+    fn scanner_for_file_and_empty_slot(converted: &[String]) -> FileAndEmptySlot {
+        let mut file_and_empty_slot = FileAndEmptySlot {
+            empty_queue: VecDeque::new(),
+            file_queue: VecDeque::new(),
+        };
+        let mut idx: i32 = (converted.len() as i32) - 1;
+        while idx >= 0 {
+            if converted[idx as usize] == "." {
+                let mut start_idx = idx;
+                while start_idx >= 0 && converted[start_idx as usize] == "." {
+                    start_idx -= 1;
+                }
+                file_and_empty_slot
+                    .empty_queue
+                    .push_front(((start_idx + 1), (idx - start_idx)));
+                idx = start_idx;
+            } else {
+                let mut start_idx = idx;
+                let file_id = converted[idx as usize].clone();
+                while start_idx >= 0
+                    && converted[start_idx as usize] != "."
+                    && converted[start_idx as usize] == file_id
+                {
+                    start_idx -= 1;
+                }
+                file_and_empty_slot.file_queue.push_back((
+                    file_id,
+                    (idx - start_idx),
+                    start_idx + 1,
+                ));
+                idx = start_idx;
+            }
+        }
+        file_and_empty_slot
+    }
+
+    fn compacted_at_once(
+        converted: &[String],
+        mut file_and_empty_slot: FileAndEmptySlot,
+    ) -> Vec<String> {
+        let mut compacted_at_once = converted.to_vec();
+        while !file_and_empty_slot.file_queue.is_empty()
+            && !file_and_empty_slot.empty_queue.is_empty()
+        {
+            // get the candidate file slot
+            let file_slot = file_and_empty_slot.file_queue.pop_front().unwrap();
+            let mut idx_of_empty_slot = 0;
+            // find the potential empty slot, especially the empty slot should be on left hand of file slot
+            while idx_of_empty_slot < file_and_empty_slot.empty_queue.len() {
+                if file_and_empty_slot.empty_queue[idx_of_empty_slot].1 >= file_slot.1
+                    && file_and_empty_slot.empty_queue[idx_of_empty_slot].0 < file_slot.2
+                {
+                    break;
+                } else {
+                    idx_of_empty_slot += 1;
+                }
+            }
+            // if the potientail empty slot found
+            if let Some(empty_slot) = file_and_empty_slot.empty_queue.remove(idx_of_empty_slot) {
+                if file_slot.1 == empty_slot.1 {
+                    // file data length match the empty slot exactly
+                    // change the "." to file_id
+                    let mut start_idx = empty_slot.0 as usize;
+                    let mut end_idx = (empty_slot.0 + empty_slot.1) as usize;
+                    let file_id = file_slot.0.clone();
+                    compacted_at_once[start_idx..end_idx].fill(file_id);
+                    // change the file_id to "."
+                    start_idx = file_slot.2 as usize;
+                    end_idx = (file_slot.2 + file_slot.1) as usize;
+                    compacted_at_once[start_idx..end_idx].fill(".".to_string());
+                } else {
+                    // file data length less than empty slot length
+                    // change the "." to file_id
+                    let mut start_idx = empty_slot.0 as usize;
+                    let mut end_idx = (empty_slot.0 + file_slot.1) as usize;
+                    let file_id = file_slot.0.clone();
+                    compacted_at_once[start_idx..end_idx].fill(file_id);
+                    // change the file_id to "."
+                    start_idx = file_slot.2 as usize;
+                    end_idx = (file_slot.2 + file_slot.1) as usize;
+                    compacted_at_once[start_idx..end_idx].fill(".".to_string());
+                    // push the rest empty space back to empty_slot queue
+                    file_and_empty_slot.empty_queue.insert(
+                        idx_of_empty_slot,
+                        (empty_slot.0 + file_slot.1, empty_slot.1 - file_slot.1),
+                    );
+                }
+            }
+            // if potential empty slot cannot be found
+            // just drop the candidate file slot
+        }
+        compacted_at_once
+    }
+
+    fn cal_check_sum_at_once(compacted_at_once: &[String]) -> u64 {
+        let mut check_sum_at_once = 0;
+        let mut idx: u64 = 0;
+        while (idx as usize) < compacted_at_once.len() {
+            if compacted_at_once[idx as usize] != "." {
+                check_sum_at_once += idx * compacted_at_once[idx as usize].parse::<u64>().unwrap();
+            }
+            idx += 1;
+        }
+        check_sum_at_once
+    }
+}
+
+impl common::Solution for Solution {
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let disk_map = input.trim_end();
+        if disk_map.is_empty() {
+            return Err(ParseError::end_of_input(input, "a disk map").into());
+        }
+        let mut origin: Vec<i16> = Vec::with_capacity(disk_map.len());
+        for (idx, num) in disk_map.char_indices() {
+            if !num.is_ascii_digit() {
+                let found = &disk_map[idx..idx + num.len_utf8()];
+                return Err(ParseError::in_line(1, disk_map, found, "a digit").into());
+            }
+            origin.push(char_to_num(num as u8));
+        }
+        Ok(Self { origin })
+    }
+
+    fn part_one(&self) -> u64 {
+        let converted = self.convert();
+        let compacted = Self::compact(&converted);
+        Self::cal_check_sum(&compacted)
+    }
+
+    fn part_two(&self) -> u64 {
+        let converted = self.convert();
+        let file_and_empty_slot = Self::scanner_for_file_and_empty_slot(&converted);
+        let compacted_at_once = Self::compacted_at_once(&converted, file_and_empty_slot);
+        Self::cal_check_sum_at_once(&compacted_at_once)
+    }
+}
+
+common::impl_from_str!(Solution);
+
+#[cfg(feature = "plugin")]
+common::export_plugin!(9, Solution);
+
+fn char_to_num<T>(c: u8) -> T
+where
+    T: From<u8>,
+{
+    T::from(c - b'0')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::manifest_path;
+
+    #[test]
+    fn test_sample() {
+        let solution = Solution::load_dataset(manifest_path!("data_test2.txt")).unwrap();
+        assert_eq!(solution.part_one(), 1928);
+        assert_eq!(solution.part_two(), 2858);
+        let solution = Solution::load_dataset(manifest_path!("data_test.txt")).unwrap();
+        assert_eq!(solution.part_one(), 3437);
+        assert_eq!(solution.part_two(), 5768);
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    io::{self, BufRead, Write},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+    time::Duration,
+};
+
+use crate::Grid;
+
+/// How an animation is played
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Playback {
+    /// the pause after each drawn frame
+    pub delay: Duration,
+    /// draw only one frame out of `skip + 1`, the simulation still runs every step
+    pub skip: usize,
+    /// start paused, each Enter then draws the next frame
+    pub paused: bool,
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(50),
+            skip: 0,
+            paused: false,
+        }
+    }
+}
+
+/// One line typed while an animation plays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    /// an empty line: pause when playing, draw the next frame when paused
+    Step,
+    Resume,
+    Quit,
+    Faster,
+    Slower,
+    Skip(usize),
+}
+
+impl Control {
+    fn parse(line: &str) -> Option<Self> {
+        match line.trim() {
+            "" => Some(Control::Step),
+            "c" => Some(Control::Resume),
+            "q" => Some(Control::Quit),
+            "+" => Some(Control::Faster),
+            "-" => Some(Control::Slower),
+            other => other.parse().ok().map(Control::Skip),
+        }
+    }
+}
+
+/// Draws a `Grid<char>` frame by frame in a terminal, with ANSI colours.
+///
+/// The simulation calls `frame` after every step and stops once it returns false.
+/// Lines read from the controls change the playback: Enter pauses and then steps one frame,
+/// `c` resumes, `q` quits, `+` and `-` halve and double the delay, and a number sets `skip`.
+pub struct Animation<W: Write> {
+    out: W,
+    playback: Playback,
+    controls: Option<Receiver<String>>,
+    frame: usize,
+    quit: bool,
+}
+
+impl<W: Write> Animation<W> {
+    pub fn new(out: W, playback: Playback) -> Self {
+        Self {
+            out,
+            playback,
+            controls: None,
+            frame: 0,
+            quit: false,
+        }
+    }
+
+    /// read the controls line by line from `controls`
+    pub fn with_controls(mut self, controls: Receiver<String>) -> Self {
+        self.controls = Some(controls);
+        self
+    }
+
+    /// read the controls from stdin, on a thread so the animation does not wait for them
+    pub fn with_stdin_controls(self) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        self.with_controls(receiver)
+    }
+
+    /// how many frames were given so far, drawn or skipped
+    pub fn frames(&self) -> usize {
+        self.frame
+    }
+
+    /// draw `grid` with a status line under it, unless the frame is skipped;
+    /// false once the viewer quit
+    pub fn frame(&mut self, grid: &Grid<char>, status: &str) -> io::Result<bool> {
+        self.frame += 1;
+        self.handle_controls();
+        if self.quit {
+            return Ok(false);
+        }
+        if !self.playback.paused && !(self.frame - 1).is_multiple_of(self.playback.skip + 1) {
+            return Ok(true);
+        }
+        self.draw(grid, status)?;
+        if self.playback.paused {
+            self.wait_while_paused();
+        } else if !self.playback.delay.is_zero() {
+            thread::sleep(self.playback.delay);
+        }
+        Ok(!self.quit)
+    }
+
+    /// draw the last state whatever `skip` says, so the end of the simulation is always seen
+    pub fn finish(&mut self, grid: &Grid<char>, status: &str) -> io::Result<()> {
+        if !self.quit {
+            self.draw(grid, status)?;
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, grid: &Grid<char>, status: &str) -> io::Result<()> {
+        let mut screen = String::new();
+        // home the cursor and draw over the last frame, the first one clears the screen
+        if self.frame <= 1 {
+            screen.push_str("\x1b[2J");
+        }
+        screen.push_str("\x1b[H");
+        for row in grid.rows() {
+            let mut current = None;
+            for c in row {
+                let colour = colour(*c);
+                if colour != current {
+                    screen.push_str(colour.unwrap_or("\x1b[0m"));
+                    current = colour;
+                }
+                screen.push(*c);
+            }
+            screen.push_str("\x1b[0m\x1b[K\n");
+        }
+        screen.push_str(status);
+        screen.push_str("\x1b[K\n\x1b[J");
+        self.out.write_all(screen.as_bytes())?;
+        self.out.flush()
+    }
+
+    fn apply(&mut self, control: Control) {
+        match control {
+            Control::Step => self.playback.paused = true,
+            Control::Resume => self.playback.paused = false,
+            Control::Quit => self.quit = true,
+            Control::Faster => self.playback.delay /= 2,
+            Control::Slower => {
+                self.playback.delay = (self.playback.delay * 2).max(Duration::from_millis(1))
+            }
+            Control::Skip(skip) => self.playback.skip = skip,
+        }
+    }
+
+    fn handle_controls(&mut self) {
+        while let Some(controls) = &self.controls {
+            match controls.try_recv() {
+                Ok(line) => {
+                    if let Some(control) = Control::parse(&line) {
+                        self.apply(control);
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                // nobody can resume a paused animation any more
+                Err(TryRecvError::Disconnected) => {
+                    self.controls = None;
+                    self.playback.paused = false;
+                }
+            }
+        }
+    }
+
+    /// block until a line says what to do next; without controls there is nobody to wait for
+    fn wait_while_paused(&mut self) {
+        while let Some(controls) = &self.controls {
+            let Ok(line) = controls.recv() else {
+                self.controls = None;
+                self.playback.paused = false;
+                break;
+            };
+            match Control::parse(&line) {
+                Some(Control::Step) => break,
+                Some(control) => {
+                    self.apply(control);
+                    if !self.playback.paused || self.quit {
+                        break;
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+/// walls are dim, the moving things bright, boxes and marks in between
+fn colour(c: char) -> Option<&'static str> {
+    match c {
+        '#' => Some("\x1b[90m"),
+        '^' | '>' | 'v' | '<' | '@' => Some("\x1b[1;93m"),
+        'O' | '[' | ']' => Some("\x1b[33m"),
+        'X' | '*' => Some("\x1b[36m"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn played(skip: usize, lines: &[&str], frames: usize) -> (String, usize) {
+        let (sender, receiver) = mpsc::channel();
+        for line in lines {
+            sender.send(line.to_string()).unwrap();
+        }
+        drop(sender);
+        let playback = Playback {
+            delay: Duration::ZERO,
+            skip,
+            paused: false,
+        };
+        let mut animation = Animation::new(Vec::new(), playback).with_controls(receiver);
+        let grid = Grid::parse("#.\n.@").unwrap();
+        let mut drawn = 0;
+        for _ in 0..frames {
+            if !animation.frame(&grid, "status").unwrap() {
+                break;
+            }
+            drawn += 1;
+        }
+        (String::from_utf8(animation.out).unwrap(), drawn)
+    }
+
+    #[test]
+    fn test_frames() {
+        let (out, drawn) = played(1, &[], 4);
+        assert_eq!(drawn, 4);
+        // frames 1 and 3 are drawn, 2 and 4 skipped
+        assert_eq!(out.matches("status").count(), 2);
+        assert!(out.contains("\x1b[90m#\x1b[0m.\x1b[0m\x1b[K\n.\x1b[1;93m@"));
+
+        let (out, drawn) = played(0, &["q"], 4);
+        assert_eq!((out.as_str(), drawn), ("", 0));
+    }
+}
@@ -0,0 +1,399 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use common::ParseError;
+
+mod animation;
+mod geometry;
+pub use animation::{Animation, Playback};
+pub use geometry::{Direction, ParseDirectionError, Point};
+
+/// the 4 steps (row, col) to the up, right, down and left neighbour
+pub const STEPS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+/// the 4 straight steps plus the 4 diagonal ones, clockwise from up
+pub const STEPS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangular map stored row by row in one `Vec`, positions are (row, col)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    /// a line is longer or shorter than the first one, lines count from 1
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// the cell parser refused a character, lines and columns count from 1
+    BadCell {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "the map is empty"),
+            ParseGridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} is {} wide, but the map is {} wide",
+                line, found, expected
+            ),
+            ParseGridError::BadCell {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "unexpected {:?} at line {}, column {}",
+                found, line, column
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+impl From<ParseGridError> for ParseError {
+    fn from(error: ParseGridError) -> Self {
+        match error {
+            ParseGridError::Empty => ParseError::new(1, 1, "a map", "end of input"),
+            ParseGridError::Ragged {
+                line,
+                expected,
+                found,
+            } => ParseError::new(
+                line,
+                found.min(expected) + 1,
+                format!("a line {} wide", expected),
+                format!("a line {} wide", found),
+            ),
+            ParseGridError::BadCell {
+                line,
+                column,
+                found,
+            } => ParseError::new(line, column, "a map cell", format!("{:?}", found)),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, cells: Vec<T>) -> Self {
+        assert_eq!(height * width, cells.len(), "cells do not fill the grid");
+        Self {
+            cells,
+            height,
+            width,
+        }
+    }
+
+    pub fn filled(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(height, width, vec![value; height * width])
+    }
+
+    /// parse one cell per character, lines are the rows and the trailing empty lines are ignored
+    pub fn parse_with<F>(input: &str, mut parse_cell: F) -> Result<Self, ParseGridError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = Vec::new();
+        let mut height = 0;
+        let mut width = 0;
+        for (idx, line) in input.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let mut line_width = 0;
+            for (col, c) in line.chars().enumerate() {
+                let cell = parse_cell(c).ok_or(ParseGridError::BadCell {
+                    line: idx + 1,
+                    column: col + 1,
+                    found: c,
+                })?;
+                cells.push(cell);
+                line_width += 1;
+            }
+            if idx == 0 {
+                width = line_width;
+            } else if line_width != width {
+                return Err(ParseGridError::Ragged {
+                    line: idx + 1,
+                    expected: width,
+                    found: line_width,
+                });
+            }
+            height += 1;
+        }
+        if height == 0 || width == 0 {
+            return Err(ParseGridError::Empty);
+        }
+        Ok(Self::new(height, width, cells))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, row: isize, col: isize) -> bool {
+        row >= 0 && col >= 0 && (row as usize) < self.height && (col as usize) < self.width
+    }
+
+    /// checked access, anything outside the map is `None`
+    pub fn get(&self, row: isize, col: isize) -> Option<&T> {
+        if self.contains(row, col) {
+            Some(&self.cells[row as usize * self.width + col as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: isize, col: isize) -> Option<&mut T> {
+        if self.contains(row, col) {
+            Some(&mut self.cells[row as usize * self.width + col as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn contains_point(&self, point: Point) -> bool {
+        self.contains(point.row, point.col)
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.row, point.col)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.row, point.col)
+    }
+
+    /// the position one step away, if it is still on the map
+    pub fn step(
+        &self,
+        (row, col): (usize, usize),
+        (d_row, d_col): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let next_row = row as isize + d_row;
+        let next_col = col as isize + d_col;
+        if self.contains(next_row, next_col) {
+            Some((next_row as usize, next_col as usize))
+        } else {
+            None
+        }
+    }
+
+    /// the up, right, down and left positions that are on the map
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        STEPS_4
+            .iter()
+            .filter_map(move |step| self.step(position, *step))
+    }
+
+    /// the 8 surrounding positions that are on the map, diagonals included
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        STEPS_8
+            .iter()
+            .filter_map(move |step| self.step(position, *step))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// the cells from `position` walking by `step` until the edge, `position` itself comes first;
+    /// rows, columns and diagonals are all rays with a different step
+    pub fn ray(&self, position: (usize, usize), step: (isize, isize)) -> impl Iterator<Item = &T> {
+        let mut next = Some(position);
+        std::iter::from_fn(move || {
+            let current = next?;
+            next = self.step(current, step);
+            Some(&self[current])
+        })
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| (idx / width, idx % width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// the first position (row by row) whose cell matches
+    pub fn position<P>(&self, predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|idx| (idx / self.width, idx % self.width))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.height, self.width, self.cells.iter().map(f).collect())
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.fill(value);
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Result<Self, ParseGridError> {
+        Self::parse_with(input, Some)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.height && col < self.width,
+            "({}, {}) is out of the grid",
+            row,
+            col
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.height && col < self.width,
+            "({}, {}) is out of the grid",
+            row,
+            col
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get_point(point)
+            .unwrap_or_else(|| panic!("{} is out of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_point_mut(point)
+            .unwrap_or_else(|| panic!("{} is out of the grid", point))
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "abc\ndef\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(MAP).unwrap();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.to_string(), MAP);
+        assert_eq!(Grid::parse(""), Err(ParseGridError::Empty));
+        assert_eq!(
+            Grid::parse("abc\nde\n"),
+            Err(ParseGridError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Grid::parse_with("12\n3x", |c| c.to_digit(10)),
+            Err(ParseGridError::BadCell {
+                line: 2,
+                column: 2,
+                found: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::parse(MAP).unwrap();
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(1, 3), None);
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.ray((0, 0), (1, 1)).collect::<String>(), "ae");
+        assert_eq!(grid.ray((1, 2), (0, -1)).collect::<String>(), "fed");
+        assert_eq!(grid.position(|c| *c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse(MAP).unwrap();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+}